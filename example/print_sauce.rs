@@ -86,10 +86,10 @@ fn main() {
                 println!("  Letter Spacing: {:?}", caps.letter_spacing);
                 println!("  Aspect Ratio:   {:?}", caps.aspect_ratio);
                 // Use font() method instead of font field
-                if let Some(font) = caps.font() {
-                    if !font.is_empty() {
-                        println!("  Font:       {}", font);
-                    }
+                if let Some(font) = caps.font()
+                    && !font.is_empty()
+                {
+                    println!("  Font:       {}", font);
                 }
             }
             Capabilities::Binary(caps) => {
@@ -133,7 +133,7 @@ fn main() {
     }
 
     // Print comments if requested
-    if args.comments && sauce.comments().len() > 0 {
+    if args.comments && !sauce.comments().is_empty() {
         println!();
         println!("Comments ({}):", sauce.comments().len());
         println!("{}", "-".repeat(40));
@@ -0,0 +1,209 @@
+//! Async reading and writing with tokio (requires the `tokio` feature).
+//!
+//! These are the non-blocking counterparts of [`SauceRecord::from_reader`],
+//! [`SauceRecord::from_path`], [`SauceRecord::write`], [`SauceRecord::write_to`] and
+//! [`SauceRecord::write_to_path`]. Only the I/O is async: the same tail windows are read
+//! and handed to the regular parser and edit planner.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # async fn example() -> icy_sauce::Result<()> {
+//! use icy_sauce::{AttachPolicy, SauceRecord, SauceRecordBuilder};
+//! use bstr::BString;
+//! use std::path::Path;
+//!
+//! let path = Path::new("art.ans");
+//! if SauceRecord::from_path_async(path).await?.is_none() {
+//!     let record = SauceRecordBuilder::default().title(BString::from("Untitled"))?.build();
+//!     record.write_to_path_async(path, &AttachPolicy::default()).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    io::{self, SeekFrom},
+    path::Path,
+};
+
+use tokio::{
+    fs::{File, OpenOptions},
+    io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt},
+};
+
+use crate::{
+    AttachPolicy, EditSummary, ParseOptions, SauceError, SauceRecord, edit::tail_is_complete,
+    header::HDR_LEN, limits::MAX_RECORD_LEN, plan::plan_attach, record::record_window_len,
+};
+
+impl SauceRecord {
+    /// Parse a SAUCE record from the end of an async seekable stream.
+    ///
+    /// See [`from_reader`](Self::from_reader).
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`]. Structural SAUCE issues yield
+    /// specific `SauceError` variants.
+    pub async fn from_async_reader<R: AsyncRead + AsyncSeek + Unpin>(
+        reader: &mut R,
+    ) -> crate::Result<Option<Self>> {
+        Self::from_async_reader_with_options(reader, &ParseOptions::default()).await
+    }
+
+    /// Parse a SAUCE record from an async seekable stream with explicit parser tolerances.
+    pub async fn from_async_reader_with_options<R: AsyncRead + AsyncSeek + Unpin>(
+        reader: &mut R,
+        options: &ParseOptions,
+    ) -> crate::Result<Option<Self>> {
+        let (offset, buf) = read_record_window(reader, options)
+            .await
+            .map_err(|e| SauceError::io_error("<reader>", e))?;
+        Self::from_window(&buf, offset, options)
+    }
+
+    /// Async variant of [`from_path`](Self::from_path).
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`].
+    pub async fn from_path_async(path: &Path) -> crate::Result<Option<Self>> {
+        Self::from_path_async_with_options(path, &ParseOptions::default()).await
+    }
+
+    /// Async variant of [`from_path_with_options`](Self::from_path_with_options).
+    pub async fn from_path_async_with_options(
+        path: &Path,
+        options: &ParseOptions,
+    ) -> crate::Result<Option<Self>> {
+        let mut file = File::open(path)
+            .await
+            .map_err(|e| SauceError::io_error(path, e))?;
+        let (offset, buf) = read_record_window(&mut file, options)
+            .await
+            .map_err(|e| SauceError::io_error(path, e))?;
+        Self::from_window(&buf, offset, options)
+    }
+
+    /// Async variant of [`write`](Self::write): writes the EOF marker and the record.
+    ///
+    /// # Errors
+    /// Write failures are wrapped in [`SauceError::IoError`].
+    pub async fn write_async<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> crate::Result<()> {
+        writer
+            .write_all(&self.to_bytes())
+            .await
+            .map_err(|e| SauceError::io_error("<writer>", e))
+    }
+
+    /// Async variant of [`write_to`](Self::write_to).
+    ///
+    /// As there, edits that would make the data shorter are refused before anything is
+    /// written; use [`write_to_path_async`](Self::write_to_path_async) for files.
+    ///
+    /// # Errors
+    /// * [`SauceError::EditWouldShrink`] if the edited data is shorter than the original.
+    /// * I/O failures are wrapped in [`SauceError::IoError`].
+    pub async fn write_to_async<W: AsyncRead + AsyncWrite + AsyncSeek + Unpin>(
+        &self,
+        handle: &mut W,
+        policy: &AttachPolicy,
+    ) -> crate::Result<EditSummary> {
+        let (offset, tail) = read_tail(handle)
+            .await
+            .map_err(|e| SauceError::io_error("<reader>", e))?;
+        let (plan, summary) = plan_attach(&tail, offset, self, policy);
+        let (keep, appended) = plan.resolve_in_place()?;
+        write_at(handle, keep, &appended)
+            .await
+            .map_err(|e| SauceError::io_error("<writer>", e))?;
+        Ok(summary)
+    }
+
+    /// Async variant of [`write_to_path`](Self::write_to_path).
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`].
+    pub async fn write_to_path_async(
+        &self,
+        path: &Path,
+        policy: &AttachPolicy,
+    ) -> crate::Result<EditSummary> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)
+            .await
+            .map_err(|e| SauceError::io_error(path, e))?;
+        let (offset, tail) = read_tail(&mut file)
+            .await
+            .map_err(|e| SauceError::io_error(path, e))?;
+        let (plan, summary) = plan_attach(&tail, offset, self, policy);
+        let (keep, appended) = plan.resolve()?;
+        write_at(&mut file, keep, &appended)
+            .await
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.set_len(summary.new_len)
+            .await
+            .map_err(|e| SauceError::io_error(path, e))?;
+        Ok(summary)
+    }
+}
+
+/// Write `bytes` at `offset` and flush.
+async fn write_at<W: AsyncWrite + AsyncSeek + Unpin>(
+    handle: &mut W,
+    offset: u64,
+    bytes: &[u8],
+) -> io::Result<()> {
+    handle.seek(SeekFrom::Start(offset)).await?;
+    handle.write_all(bytes).await?;
+    handle.flush().await
+}
+
+/// Async variant of `record::read_record_window`.
+async fn read_record_window<R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut R,
+    options: &ParseOptions,
+) -> io::Result<(u64, Vec<u8>)> {
+    let stream_len = reader.seek(SeekFrom::End(0)).await?;
+    let mut buf = read_last(reader, stream_len, HDR_LEN + 1, Vec::new()).await?;
+    let needed = record_window_len(&buf, options);
+    if needed > buf.len() {
+        buf = read_last(reader, stream_len, needed, buf).await?;
+        reader.seek(SeekFrom::End(0)).await?;
+    }
+    Ok((stream_len - buf.len() as u64, buf))
+}
+
+/// Async variant of `record::read_last`.
+async fn read_last<R: AsyncRead + AsyncSeek + Unpin>(
+    reader: &mut R,
+    stream_len: u64,
+    len: usize,
+    known: Vec<u8>,
+) -> io::Result<Vec<u8>> {
+    let len = (len as u64).min(stream_len);
+    let missing = (len - known.len() as u64) as usize;
+    reader.seek(SeekFrom::Start(stream_len - len)).await?;
+    let mut buf = vec![0u8; missing];
+    reader.read_exact(&mut buf).await?;
+    buf.extend(known);
+    Ok(buf)
+}
+
+/// Async variant of `edit::read_tail_from`.
+async fn read_tail<R: AsyncRead + AsyncSeek + Unpin>(reader: &mut R) -> io::Result<(u64, Vec<u8>)> {
+    let stream_len = reader.seek(SeekFrom::End(0)).await?;
+    let mut window = MAX_RECORD_LEN as u64;
+    loop {
+        let read_len = window.min(stream_len);
+        let offset = stream_len - read_len;
+        reader.seek(SeekFrom::Start(offset)).await?;
+        let mut tail = vec![0u8; read_len as usize];
+        reader.read_exact(&mut tail).await?;
+        if tail_is_complete(offset, &tail) {
+            return Ok((offset, tail));
+        }
+        window *= 2;
+    }
+}
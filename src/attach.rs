@@ -0,0 +1,106 @@
+//! Attaching SAUCE records to in-memory data.
+//!
+//! [`attach_sauce`] bundles the usual "strip the old record, make sure there is one EOF
+//! marker, append the new record" sequence into one call.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{AttachPolicy, SauceRecord, SauceRecordBuilder, attach_sauce};
+//! use bstr::BString;
+//!
+//! let mut data = b"Content".to_vec();
+//! let record = SauceRecordBuilder::default().title(BString::from("First"))?.build();
+//! attach_sauce(&mut data, &record, &AttachPolicy::default())?;
+//!
+//! let record = SauceRecordBuilder::default().title(BString::from("Second"))?.build();
+//! let summary = attach_sauce(&mut data, &record, &AttachPolicy::default())?;
+//! assert_eq!(summary.records_removed, 1);
+//! assert_eq!(summary.file_size, Some(7));
+//!
+//! let parsed = SauceRecord::from_bytes(&data)?.unwrap();
+//! assert_eq!(parsed.title(), &BString::from("Second"));
+//! assert_eq!(parsed.file_size(), 7);
+//! assert_eq!(data.len(), 7 + 1 + 128);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{SauceRecord, plan::plan_attach};
+use alloc::vec::Vec;
+
+/// What happens to SAUCE records already present in the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttachMode {
+    /// Replace the last record; older stacked records stay.
+    #[default]
+    Replace,
+    /// Remove all contiguous records before attaching (see [`StripMode::All`](crate::StripMode::All)).
+    ReplaceAll,
+    /// Keep existing records and append the new one on top.
+    Stack,
+}
+
+/// Controls [`attach_sauce`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachPolicy {
+    /// How existing records are treated.
+    pub mode: AttachMode,
+    /// Set the record's `file_size` to the length of the content.
+    ///
+    /// Content longer than `u32::MAX` bytes gets a `file_size` of 0 (unknown).
+    pub fill_file_size: bool,
+    /// Remove all EOF (0x1A) bytes directly preceding the new record so that exactly
+    /// one EOF marker separates it from the content before it. Has no effect when the
+    /// new record is placed directly on top of an existing one.
+    ///
+    /// Without this, only the EOF marker belonging to a removed record is dropped.
+    /// Off by default, as 0x1A is a valid character or attribute byte in BinaryText
+    /// and XBin content.
+    pub normalize_eof: bool,
+}
+
+impl Default for AttachPolicy {
+    /// Replace the last record and fill `file_size`; the content is left untouched.
+    fn default() -> Self {
+        Self {
+            mode: AttachMode::Replace,
+            fill_file_size: true,
+            normalize_eof: false,
+        }
+    }
+}
+
+/// What [`attach_sauce`], [`SauceRecord::write_to_path`] or
+/// [`SauceRecord::remove_from_path`] changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditSummary {
+    /// Number of SAUCE records removed.
+    pub records_removed: usize,
+    /// Number of EOF (0x1A) bytes removed.
+    pub eof_bytes_removed: usize,
+    /// Length of the content, without EOF markers and SAUCE records.
+    pub content_len: u64,
+    /// Length of the data after the edit.
+    pub new_len: u64,
+    /// The `file_size` written, if [`AttachPolicy::fill_file_size`] was set.
+    /// Always `None` when removing records.
+    pub file_size: Option<u32>,
+}
+
+/// Attach `record` to `data`, removing or keeping existing records according to `policy`.
+///
+/// The new record is always written with a leading EOF marker.
+///
+/// Use [`EditPlan::for_bytes`](crate::EditPlan::for_bytes) to preview the change.
+///
+/// # Errors
+/// Does not fail for in-memory data; the `Result` mirrors the file based variants.
+pub fn attach_sauce(
+    data: &mut Vec<u8>,
+    record: &SauceRecord,
+    policy: &AttachPolicy,
+) -> crate::Result<EditSummary> {
+    let (plan, summary) = plan_attach(data, 0, record, policy);
+    plan.apply_to_vec(data)?;
+    Ok(summary)
+}
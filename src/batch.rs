@@ -0,0 +1,327 @@
+//! All-or-nothing SAUCE edits of many files.
+//!
+//! A [`BatchEdit`] collects `(path, record)` pairs. [`BatchEdit::apply`] first plans every
+//! edit, then writes a [`Journal`] with the original tail of each file (everything from
+//! the first byte the edit changes, at most one stack of records) and syncs it to disk
+//! before any file is touched. If an edit fails, the files already changed are restored
+//! from the journal. The journal stays on disk, so the whole batch can be undone later
+//! with [`Journal::load`] and [`Journal::rollback`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use icy_sauce::{AttachPolicy, BatchEdit, Journal, SauceRecordBuilder};
+//! use bstr::BString;
+//! use std::path::Path;
+//!
+//! let record = SauceRecordBuilder::default().group(BString::from("Group"))?.build();
+//! let mut batch = BatchEdit::new(AttachPolicy::default());
+//! batch.add("pack/a.ans", record.clone());
+//! batch.add("pack/b.ans", record);
+//! batch.apply(Path::new("pack.journal"))?;
+//!
+//! // Later: undo the whole batch.
+//! Journal::load(Path::new("pack.journal"))?.rollback()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{
+    fmt::Write as _,
+    fs::{self, File, OpenOptions},
+    io::{Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    AttachPolicy, EditPlan, SauceError, SauceRecord,
+    edit::{open_rw, read_tail},
+    plan::plan_attach,
+    util::{from_hex, to_hex},
+};
+
+/// First line of a serialized journal.
+const JOURNAL_MAGIC: &str = "SAUCE-BATCH-JOURNAL 1";
+
+/// A set of SAUCE edits applied to many files as one transaction.
+#[derive(Debug, Clone, Default)]
+pub struct BatchEdit {
+    policy: AttachPolicy,
+    edits: Vec<(PathBuf, SauceRecord)>,
+}
+
+impl BatchEdit {
+    /// Create an empty batch attaching records according to `policy`.
+    pub fn new(policy: AttachPolicy) -> Self {
+        Self {
+            policy,
+            edits: Vec::new(),
+        }
+    }
+
+    /// Queue attaching `record` to the file at `path`.
+    pub fn add(&mut self, path: impl Into<PathBuf>, record: SauceRecord) -> &mut Self {
+        self.edits.push((path.into(), record));
+        self
+    }
+
+    /// Number of queued edits.
+    pub fn len(&self) -> usize {
+        self.edits.len()
+    }
+
+    /// Returns `true` if no edits are queued.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Apply all queued edits, journaling the original file tails to `journal_path` first.
+    ///
+    /// Nothing is changed if planning or writing the journal fails, e.g. because a file
+    /// already exists at `journal_path` (see [`Journal::save`]). If an edit fails, all
+    /// files changed so far are rolled back and the edit's error is returned. On success
+    /// the journal is returned and left on disk for a later [`Journal::rollback`].
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`]; paths that cannot be stored
+    /// in a journal (not UTF-8 or containing a line break) give [`SauceError::InvalidJournal`].
+    pub fn apply(&self, journal_path: &Path) -> crate::Result<Journal> {
+        let mut plans = Vec::with_capacity(self.edits.len());
+        let mut journal = Journal::default();
+        for (path, record) in &self.edits {
+            let mut file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+            let (offset, tail) = read_tail(&mut file, path)?;
+            let (plan, _) = plan_attach(&tail, offset, record, &self.policy);
+            let (keep, _) = plan.resolve()?;
+            journal.entries.push(JournalEntry {
+                path: path.clone(),
+                offset: keep,
+                original_tail: tail[(keep - offset) as usize..].to_vec(),
+                new_len: plan.new_len(),
+            });
+            plans.push(plan);
+        }
+        journal.save(journal_path)?;
+
+        for (i, (plan, entry)) in plans.iter().zip(&journal.entries).enumerate() {
+            let mut started = false;
+            if let Err(err) = apply_synced(plan, &entry.path, &mut started) {
+                // A file that failed its length check was never touched by this batch
+                if started && let Err(restore_err) = entry.write_back() {
+                    log::warn!(
+                        "Failed to restore '{}': {restore_err}",
+                        entry.path.display()
+                    );
+                }
+                let applied = Journal {
+                    entries: journal.entries[..i].to_vec(),
+                };
+                if let Err(rollback_err) = applied.rollback() {
+                    log::warn!("Rollback after failed batch edit failed: {rollback_err}");
+                }
+                return Err(err);
+            }
+        }
+        Ok(journal)
+    }
+}
+
+/// Apply `plan` to `path` and sync it. `started` is set once the file passed the
+/// plan's length check and may have been changed.
+fn apply_synced(plan: &EditPlan, path: &Path, started: &mut bool) -> crate::Result<()> {
+    let mut file = open_rw(path)?;
+    let len = file
+        .metadata()
+        .map_err(|e| SauceError::io_error(path, e))?
+        .len();
+    plan.check_len(len)?;
+    *started = true;
+    plan.apply_to_file(&mut file, path)?;
+    file.sync_all().map_err(|e| SauceError::io_error(path, e))
+}
+
+/// The original tail of one file edited by a [`BatchEdit`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// The edited file.
+    pub path: PathBuf,
+    /// Offset of the first byte the edit may change.
+    pub offset: u64,
+    /// The original bytes from `offset` to the end of the file.
+    pub original_tail: Vec<u8>,
+    /// Length of the file after the edit.
+    pub new_len: u64,
+}
+
+impl JournalEntry {
+    /// Length of the file before the edit.
+    pub fn original_len(&self) -> u64 {
+        self.offset + self.original_tail.len() as u64
+    }
+
+    /// Restore the original tail, unless the file was changed again after the edit.
+    fn restore(&self) -> crate::Result<()> {
+        let path = &self.path;
+        let len = fs::metadata(path)
+            .map_err(|e| SauceError::io_error(path, e))?
+            .len();
+        if len != self.new_len && len != self.original_len() {
+            return Err(SauceError::InvalidJournal(format!(
+                "'{}' has {len} bytes, neither the edited ({}) nor the original ({}) length",
+                path.display(),
+                self.new_len,
+                self.original_len()
+            )));
+        }
+        self.write_back()
+    }
+
+    /// Truncate the file to `offset` and write the original tail back.
+    fn write_back(&self) -> crate::Result<()> {
+        let path = &self.path;
+        let mut file = open_rw(path)?;
+        file.set_len(self.offset)
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.seek(SeekFrom::Start(self.offset))
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.write_all(&self.original_tail)
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.sync_all().map_err(|e| SauceError::io_error(path, e))
+    }
+}
+
+/// Undo information written by [`BatchEdit::apply`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Journal {
+    /// One entry per edit, in the order the edits were applied.
+    pub entries: Vec<JournalEntry>,
+}
+
+impl Journal {
+    /// Restore the original tails of all journaled files, last edit first.
+    ///
+    /// Rolling back is idempotent: files already in their original state are rewritten
+    /// with the same bytes. Every entry is attempted even if one fails.
+    ///
+    /// # Errors
+    /// The first failure; [`SauceError::InvalidJournal`] if a file has neither its edited
+    /// nor its original length (it was changed again since).
+    pub fn rollback(&self) -> crate::Result<()> {
+        let mut result = Ok(());
+        for entry in self.entries.iter().rev() {
+            if let Err(err) = entry.restore() {
+                log::warn!("Failed to restore '{}': {err}", entry.path.display());
+                if result.is_ok() {
+                    result = Err(err);
+                }
+            }
+        }
+        result
+    }
+
+    /// Read a journal written by [`save`](Self::save).
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`], malformed content gives
+    /// [`SauceError::InvalidJournal`].
+    pub fn load(path: &Path) -> crate::Result<Self> {
+        let text = fs::read_to_string(path).map_err(|e| SauceError::io_error(path, e))?;
+        Self::parse(&text)
+    }
+
+    /// Write the journal to a new file at `path` and sync it to disk.
+    ///
+    /// An existing file is never overwritten, so the undo data of an earlier batch
+    /// cannot be lost by reusing its journal path.
+    ///
+    /// # Errors
+    /// As [`serialize`](Self::serialize); I/O failures, including an already existing
+    /// `path`, are wrapped in [`SauceError::IoError`].
+    pub fn save(&self, path: &Path) -> crate::Result<()> {
+        let text = self.serialize()?;
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.write_all(text.as_bytes())
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.sync_all().map_err(|e| SauceError::io_error(path, e))
+    }
+
+    /// Serialize the journal to a line based text format.
+    ///
+    /// ```text
+    /// SAUCE-BATCH-JOURNAL 1
+    /// file pack/a.ans
+    /// offset 1000
+    /// new-len 1129
+    /// tail 1a5341554345...
+    /// ```
+    ///
+    /// # Errors
+    /// [`SauceError::InvalidJournal`] if a path is not UTF-8 or contains a line break.
+    pub fn serialize(&self) -> crate::Result<String> {
+        let mut out = String::new();
+        let _ = writeln!(out, "{JOURNAL_MAGIC}");
+        for entry in &self.entries {
+            let path = entry
+                .path
+                .to_str()
+                .filter(|p| !p.contains(['\n', '\r']))
+                .ok_or_else(|| {
+                    SauceError::InvalidJournal(format!(
+                        "path '{}' cannot be stored in a journal",
+                        entry.path.display()
+                    ))
+                })?;
+            let _ = writeln!(out, "file {path}");
+            let _ = writeln!(out, "offset {}", entry.offset);
+            let _ = writeln!(out, "new-len {}", entry.new_len);
+            let _ = writeln!(out, "tail {}", to_hex(&entry.original_tail));
+        }
+        Ok(out)
+    }
+
+    /// Parse a journal produced by [`serialize`](Self::serialize).
+    ///
+    /// # Errors
+    /// [`SauceError::InvalidJournal`] describing the first malformed line.
+    pub fn parse(text: &str) -> crate::Result<Self> {
+        let invalid = |msg: String| SauceError::InvalidJournal(msg);
+        let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+        if lines.next().map(str::trim) != Some(JOURNAL_MAGIC) {
+            return Err(invalid("missing journal header".into()));
+        }
+
+        let mut entries = Vec::new();
+        while let Some(line) = lines.next() {
+            let Some(path) = line.strip_prefix("file ") else {
+                return Err(invalid(format!("expected 'file', found '{line}'")));
+            };
+            let mut field = |name: &str| {
+                lines
+                    .next()
+                    .map(|l| l.trim().split_once(' ').unwrap_or((l.trim(), "")))
+                    .filter(|(key, _)| *key == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| invalid(format!("missing {name} for '{path}'")))
+            };
+            let offset = parse_u64(field("offset")?)?;
+            let new_len = parse_u64(field("new-len")?)?;
+            let original_tail = from_hex(field("tail")?).map_err(invalid)?;
+            entries.push(JournalEntry {
+                path: PathBuf::from(path),
+                offset,
+                original_tail,
+                new_len,
+            });
+        }
+        Ok(Self { entries })
+    }
+}
+
+fn parse_u64(text: &str) -> crate::Result<u64> {
+    text.trim()
+        .parse()
+        .map_err(|_| SauceError::InvalidJournal(format!("invalid number '{text}'")))
+}
@@ -31,6 +31,7 @@
 //!     .build();
 //! ```
 
+use alloc::vec::Vec;
 use bstr::BString;
 
 use crate::{
@@ -66,7 +67,7 @@ use crate::{
 /// - String fields that exceed their maximum length
 /// - Comment count exceeds 255
 /// - Individual comment length exceeds 64 bytes
-#[derive(Default)]
+#[derive(Debug, Clone, Default)]
 pub struct SauceRecordBuilder {
     /// Raw SAUCE header being constructed
     pub(crate) header: SauceHeader,
@@ -444,10 +445,6 @@ impl SauceRecordBuilder {
     /// sauce.write(&mut output).unwrap();
     /// ```
     pub fn build(self) -> crate::SauceRecord {
-        crate::SauceRecord {
-            header: self.header,
-            comments: self.comments,
-            cached_caps: std::cell::OnceCell::new(),
-        }
+        crate::SauceRecord::new(self.header, self.comments)
     }
 }
@@ -513,14 +513,14 @@ impl AudioCapabilities {
     /// header.data_type = SauceDataType::Audio;
     /// header.file_type = 3; // S3M
     /// header.t_info1 = 44100;
-    /// use std::convert::TryFrom;
+    /// use core::convert::TryFrom;
     /// let caps = AudioCapabilities::try_from(&header).unwrap();
     /// assert_eq!(caps.format, AudioFormat::S3m);
     /// assert_eq!(caps.sample_rate, 44100);
     /// ```
     /// The bespoke internal `from(&SauceHeader)` has been replaced by a `TryFrom<&SauceHeader>`
     /// implementation below.
-
+    ///
     /// Serialize audio capabilities into a SAUCE header.
     ///
     /// # Arguments
@@ -192,7 +192,7 @@ impl BinaryCapabilities {
     /// assert_eq!(binary_text.columns, 80);
     /// ```
     pub fn binary_text(columns: u16) -> crate::Result<Self> {
-        if columns == 0 || columns % 2 != 0 || columns > 510 {
+        if columns == 0 || !columns.is_multiple_of(2) || columns > 510 {
             return Err(SauceError::BinFileWidthLimitExceeded(columns as i32));
         }
         Ok(Self {
@@ -352,14 +352,14 @@ impl BinaryCapabilities {
     /// let mut header = SauceHeader::default();
     /// header.data_type = SauceDataType::BinaryText;
     /// header.file_type = 40; // width = 80
-    /// use std::convert::TryFrom;
+    /// use core::convert::TryFrom;
     /// let caps = BinaryCapabilities::try_from(&header).unwrap();
     /// assert_eq!(caps.columns, 80);
     /// ```
     /// This example uses the `TryFrom<&SauceHeader>` implementation on `BinaryCapabilities`.
     /// The bespoke `from(&SauceHeader)` constructor has been removed in favor of the
     /// standard conversion trait.
-
+    ///
     /// Serialize binary text capabilities into a SAUCE header.
     ///
     /// # Arguments
@@ -401,7 +401,7 @@ impl BinaryCapabilities {
         match self.format {
             BinaryFormat::BinaryText => {
                 header.data_type = SauceDataType::BinaryText;
-                if self.columns == 0 || self.columns % 2 != 0 || self.columns > 510 {
+                if self.columns == 0 || !self.columns.is_multiple_of(2) || self.columns > 510 {
                     return Err(SauceError::BinFileWidthLimitExceeded(self.columns as i32));
                 }
                 header.file_type = (self.columns / 2) as u8;
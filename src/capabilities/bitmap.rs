@@ -324,14 +324,14 @@ impl BitmapCapabilities {
     /// header.t_info1 = 640;
     /// header.t_info2 = 480;
     /// header.t_info3 = 24;
-    /// use std::convert::TryFrom;
+    /// use core::convert::TryFrom;
     /// let caps = BitmapCapabilities::try_from(&header).unwrap();
     /// assert_eq!(caps.format, BitmapFormat::Png);
     /// assert_eq!(caps.width, 640);
     /// ```
     /// The bespoke internal `from(&SauceHeader)` has been replaced by a `TryFrom<&SauceHeader>`
     /// implementation for idiomatic conversion.
-
+    ///
     /// Serialize graphics capabilities into a SAUCE header.
     ///
     /// # Arguments
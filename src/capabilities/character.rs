@@ -405,10 +405,10 @@ impl CharacterCapabilities {
         font: Option<BString>,
     ) -> crate::Result<Self> {
         // Validate font length if provided
-        if let Some(ref f) = font {
-            if f.len() > limits::MAX_FONT_NAME_LENGTH {
-                return Err(SauceError::FontNameTooLong(f.len()));
-            }
+        if let Some(ref f) = font
+            && f.len() > limits::MAX_FONT_NAME_LENGTH
+        {
+            return Err(SauceError::FontNameTooLong(f.len()));
         }
 
         Ok(CharacterCapabilities {
@@ -79,7 +79,7 @@ impl ExecutableCapabilities {
 
     /// Parse executable capabilities from a SAUCE header via `TryFrom<&SauceHeader>`.
     /// The bespoke internal `from(&SauceHeader)` has been removed.
-
+    ///
     /// Serialize executable capabilities into a SAUCE header.
     ///
     /// # Arguments
@@ -116,7 +116,7 @@ pub use crate::vector::{VectorCapabilities, VectorFormat};
 /// // Parse from header
 /// let mut header = SauceHeader::default();
 /// header.data_type = SauceDataType::Character;
-/// use std::convert::TryFrom;
+/// use core::convert::TryFrom;
 /// let char_caps = CharacterCapabilities::try_from(&header).unwrap();
 /// let caps = Capabilities::Character(char_caps);
 ///
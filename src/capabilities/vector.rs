@@ -83,7 +83,7 @@ impl VectorCapabilities {
 
     /// Parse vector capabilities from a SAUCE header via the `TryFrom<&SauceHeader>` implementation.
     /// The former bespoke `from(&SauceHeader)` has been removed in favor of the standard trait.
-
+    ///
     /// Serialize vector capabilities into a SAUCE header.
     pub(crate) fn encode_into_header(&self, header: &mut SauceHeader) -> crate::Result<()> {
         header.data_type = SauceDataType::Vector;
@@ -0,0 +1,155 @@
+//! Confidence scoring for detected SAUCE records.
+//!
+//! Any data ending with the bytes `SAUCE00` plus 121 more bytes parses as a record, so
+//! text files that merely talk about SAUCE produce records with garbage fields. A
+//! [`Confidence`] combines a few cheap sanity checks into a score from 0 to 100:
+//!
+//! | Check                                     | Weight |
+//! |-------------------------------------------|--------|
+//! | [`ConfidenceCheck::DateDigits`]           | 25     |
+//! | [`ConfidenceCheck::KnownDataType`]        | 25     |
+//! | [`ConfidenceCheck::PlausibleFileSize`]    | 20     |
+//! | [`ConfidenceCheck::EofMarker`]            | 15     |
+//! | [`ConfidenceCheck::PrintableMetadata`]    | 15     |
+//!
+//! Set [`ParseOptions::min_confidence`](crate::ParseOptions::min_confidence) to make the
+//! parser ignore records below a threshold.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{ConfidenceCheck, SauceRecord, SauceRecordBuilder};
+//!
+//! let mut data = b"Content".to_vec();
+//! SauceRecordBuilder::default().file_size(7).build().write(&mut data)?;
+//!
+//! let record = SauceRecord::from_bytes(&data)?.unwrap();
+//! assert_eq!(record.confidence(&data).score, 100);
+//!
+//! let record = SauceRecord::from_bytes(&data[8..])?.unwrap();
+//! let confidence = record.confidence(&data[8..]);
+//! assert_eq!(confidence.score, 65);
+//! assert_eq!(
+//!     confidence.failed,
+//!     [ConfidenceCheck::PlausibleFileSize, ConfidenceCheck::EofMarker]
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::{SauceDataType, SauceRecord, limits};
+
+/// A single sanity check contributing to a [`Confidence`] score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConfidenceCheck {
+    /// The date field consists of 8 ASCII digits.
+    DateDigits,
+    /// The data type is one of the types defined by the spec.
+    KnownDataType,
+    /// The file size is 0 (unknown) or matches the length of the content before the record.
+    PlausibleFileSize,
+    /// An EOF (0x1A) marker directly precedes the record.
+    EofMarker,
+    /// Title, author and group contain no control characters.
+    PrintableMetadata,
+}
+
+impl ConfidenceCheck {
+    /// All checks in evaluation order.
+    pub const ALL: [ConfidenceCheck; 5] = [
+        ConfidenceCheck::DateDigits,
+        ConfidenceCheck::KnownDataType,
+        ConfidenceCheck::PlausibleFileSize,
+        ConfidenceCheck::EofMarker,
+        ConfidenceCheck::PrintableMetadata,
+    ];
+
+    /// Points this check contributes to the score when it passes.
+    pub fn weight(self) -> u8 {
+        match self {
+            ConfidenceCheck::DateDigits => 25,
+            ConfidenceCheck::KnownDataType => 25,
+            ConfidenceCheck::PlausibleFileSize => 20,
+            ConfidenceCheck::EofMarker => 15,
+            ConfidenceCheck::PrintableMetadata => 15,
+        }
+    }
+}
+
+impl Display for ConfidenceCheck {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConfidenceCheck::DateDigits => write!(f, "date is not numeric"),
+            ConfidenceCheck::KnownDataType => write!(f, "unknown data type"),
+            ConfidenceCheck::PlausibleFileSize => write!(f, "file size does not match content"),
+            ConfidenceCheck::EofMarker => write!(f, "no EOF marker before record"),
+            ConfidenceCheck::PrintableMetadata => write!(f, "control characters in metadata"),
+        }
+    }
+}
+
+/// How likely a detected record is a genuine SAUCE record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Confidence {
+    /// Sum of the weights of all passed checks (0..=100).
+    pub score: u8,
+    /// The checks that did not pass, in evaluation order.
+    pub failed: Vec<ConfidenceCheck>,
+}
+
+impl Confidence {
+    /// Returns `true` if `check` passed.
+    pub fn passed(&self, check: ConfidenceCheck) -> bool {
+        !self.failed.contains(&check)
+    }
+}
+
+/// Score `record`, whose header ends at `end` within `data`.
+pub(crate) fn evaluate(data: &[u8], end: usize, record: &SauceRecord) -> Confidence {
+    evaluate_at(data, end, 0, record)
+}
+
+/// Like [`evaluate`], for a `data` window that starts `offset` bytes into the stream, so
+/// `FileSize` is checked against the real content length.
+pub(crate) fn evaluate_at(
+    data: &[u8],
+    end: usize,
+    offset: u64,
+    record: &SauceRecord,
+) -> Confidence {
+    let header = record.header();
+    let start = end.saturating_sub(header.total_length());
+    let eof_marker = start > 0 && data[start - 1] == 0x1A;
+    let content_len = offset + if eof_marker { start - 1 } else { start } as u64;
+
+    let date_start = end - crate::header::HDR_LEN + crate::header::field::DATE.start;
+    let date = &data[date_start..date_start + limits::DATE_LENGTH];
+
+    let printable = |text: &[u8]| text.iter().all(|&b| b >= 0x20 && b != 0x7F);
+
+    let mut score = 0;
+    let mut failed = Vec::new();
+    for check in ConfidenceCheck::ALL {
+        let passed = match check {
+            ConfidenceCheck::DateDigits => date.iter().all(u8::is_ascii_digit),
+            ConfidenceCheck::KnownDataType => {
+                !matches!(header.data_type, SauceDataType::Undefined(_))
+            }
+            ConfidenceCheck::PlausibleFileSize => {
+                header.file_size == 0 || u64::from(header.file_size) == content_len
+            }
+            ConfidenceCheck::EofMarker => eof_marker,
+            ConfidenceCheck::PrintableMetadata => {
+                printable(&header.title) && printable(&header.author) && printable(&header.group)
+            }
+        };
+        if passed {
+            score += check.weight();
+        } else {
+            failed.push(check);
+        }
+    }
+    Confidence { score, failed }
+}
@@ -66,6 +66,8 @@
 //! # }
 //! ```
 
+use alloc::format;
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct SauceDate {
     /// Full 4‑digit year (0–9999 typical; values outside are allowed but will
@@ -77,7 +79,7 @@ pub struct SauceDate {
     pub day: u8,
 }
 
-impl std::fmt::Display for SauceDate {
+impl core::fmt::Display for SauceDate {
     /// Format as human‑friendly `YYYY/MM/DD` if year is in `[0, 9999]`,
     /// otherwise fall back to unpadded year with slashes:
     /// ```
@@ -85,7 +87,7 @@ impl std::fmt::Display for SauceDate {
     /// assert_eq!(SauceDate::new(2025, 1, 2).to_string(), "2025/01/02");
     /// assert_eq!(SauceDate::new(12_345, 1, 2).to_string(), "12345/01/02");
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.year >= 0 && self.year < 10_000 {
             write!(f, "{:04}/{:02}/{:02}", self.year, self.month, self.day)
         } else {
@@ -154,10 +156,9 @@ impl SauceDate {
     /// SauceDate::new(2025, 11, 8).write(&mut buf).unwrap();
     /// assert_eq!(&buf, b"20251108");
     /// ```
-    pub fn write<A: std::io::Write>(&self, writer: &mut A) -> crate::Result<()> {
-        write!(writer, "{:04}{:02}{:02}", self.year, self.month, self.day)
-            .map_err(|e| crate::SauceError::io_error("<date>", e))?;
-        Ok(())
+    pub fn write<A: crate::ByteSink>(&self, writer: &mut A) -> crate::Result<()> {
+        let text = format!("{:04}{:02}{:02}", self.year, self.month, self.day);
+        writer.write_bytes(text.as_bytes())
     }
 
     /// Attempt conversion to `chrono::NaiveDate` if the feature is enabled,
@@ -198,7 +199,7 @@ impl From<chrono::NaiveDate> for SauceDate {
 }
 
 #[cfg(feature = "chrono")]
-impl std::convert::TryFrom<SauceDate> for chrono::NaiveDate {
+impl core::convert::TryFrom<SauceDate> for chrono::NaiveDate {
     type Error = ();
     /// Fallible conversion performing range validation.
     ///
@@ -218,7 +219,7 @@ impl std::convert::TryFrom<SauceDate> for chrono::NaiveDate {
 }
 
 #[cfg(feature = "chrono")]
-impl std::convert::TryFrom<&SauceDate> for chrono::NaiveDate {
+impl core::convert::TryFrom<&SauceDate> for chrono::NaiveDate {
     type Error = ();
     fn try_from(value: &SauceDate) -> Result<Self, Self::Error> {
         chrono::NaiveDate::from_ymd_opt(value.year, value.month as u32, value.day as u32).ok_or(())
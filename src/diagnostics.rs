@@ -0,0 +1,83 @@
+//! Parse diagnostics.
+//!
+//! The parser tolerates a number of spec deviations (see [`ParseOptions`](crate::ParseOptions)).
+//! Every tolerated deviation is logged through the `log` crate and, when parsing with
+//! [`SauceRecord::from_bytes_with_diagnostics`](crate::SauceRecord::from_bytes_with_diagnostics),
+//! also collected as a [`Diagnostic`] so callers can attribute problems to a specific file
+//! without installing a global logger.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{DiagnosticKind, ParseOptions, SauceRecord, SauceRecordBuilder};
+//!
+//! let mut data = b"Content".to_vec();
+//! SauceRecordBuilder::default().build().write_without_eof(&mut data)?;
+//!
+//! let (record, diagnostics) =
+//!     SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::default())?;
+//! assert!(record.is_some());
+//! assert_eq!(diagnostics[0].kind, DiagnosticKind::MissingEofMarker);
+//! assert_eq!(diagnostics[0].offset, 6); // byte where the EOF marker was expected
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Display;
+
+/// The kind of problem a [`Diagnostic`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticKind {
+    /// The version field is not `"00"` but was accepted.
+    UnknownVersion,
+    /// The date field is not 8 ASCII digits; the date reads as `0000/00/00`.
+    InvalidDate,
+    /// The comment block does not start with the `COMNT` ID.
+    MissingCommentId,
+    /// The header's comment count does not match the comment block found by scanning
+    /// (see [`ParseOptions::scan_for_comments`](crate::ParseOptions::scan_for_comments)).
+    /// The offset points at the `COMNT` ID that was used.
+    CommentCountMismatch,
+    /// The data is too short to contain the announced comment block.
+    ///
+    /// The block would start before the data, so the offset is always 0.
+    TruncatedCommentBlock,
+    /// No EOF (0x1A) marker directly precedes the SAUCE record.
+    MissingEofMarker,
+    /// The record scored below [`ParseOptions::min_confidence`](crate::ParseOptions::min_confidence)
+    /// and was ignored.
+    LowConfidence,
+}
+
+/// A single tolerated problem found while parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// What kind of problem was found.
+    pub kind: DiagnosticKind,
+    /// Byte offset into the parsed data where the problem was found.
+    pub offset: usize,
+    /// Human readable description (same text that is logged).
+    pub message: String,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "offset {}: {}", self.offset, self.message)
+    }
+}
+
+/// Log a diagnostic and record it in `diagnostics`.
+pub(crate) fn emit(
+    diagnostics: &mut Vec<Diagnostic>,
+    kind: DiagnosticKind,
+    offset: usize,
+    message: impl Into<String>,
+) {
+    let message = message.into();
+    log::warn!("{}", message);
+    diagnostics.push(Diagnostic {
+        kind,
+        offset,
+        message,
+    });
+}
@@ -0,0 +1,272 @@
+//! In-place editing of SAUCE records in files on disk.
+//!
+//! [`SauceRecord::write_to_path`] and [`SauceRecord::remove_from_path`] only read the tail
+//! of the file, truncate it with [`File::set_len`] and append the new record. The content
+//! before the record is never read or rewritten, which keeps edits of large XBin/RIP
+//! collections cheap.
+//!
+//! The `_atomic` variants write the complete new file to a temporary sibling, sync it
+//! and rename it over the original, so a crash leaves either the old or the new file
+//! behind. Permissions and access/modification times of the original are kept.
+//!
+//! [`SauceRecord::write_to`] performs the same edit through any `Read + Write + Seek`
+//! handle, e.g. an entry in a container or virtual filesystem.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use icy_sauce::{AttachPolicy, SauceRecord, SauceRecordBuilder, StripMode};
+//! use bstr::BString;
+//! use std::path::Path;
+//!
+//! let path = Path::new("art.xb");
+//! let record = SauceRecordBuilder::default().title(BString::from("Title"))?.build();
+//! record.write_to_path(path, &AttachPolicy::default())?;
+//!
+//! SauceRecord::remove_from_path(path, StripMode::default())?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{
+    fs::{self, File, FileTimes, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    AttachPolicy, EditSummary, SauceError, SauceRecord, StripMode,
+    limits::MAX_RECORD_LEN,
+    plan::{plan_attach, plan_remove},
+    strip_sauce,
+};
+
+impl SauceRecord {
+    /// Attach this record to the file at `path`, like [`attach_sauce`](crate::attach_sauce)
+    /// does for a buffer.
+    ///
+    /// Only the tail of the file is read. The file is truncated to the end of the content
+    /// and the EOF marker and record are appended; content before the old record is left
+    /// untouched. Use [`EditPlan::for_path`](crate::EditPlan::for_path) to preview the change.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`].
+    pub fn write_to_path(&self, path: &Path, policy: &AttachPolicy) -> crate::Result<EditSummary> {
+        let mut file = open_rw(path)?;
+        let (offset, tail) = read_tail(&mut file, path)?;
+        let (plan, summary) = plan_attach(&tail, offset, self, policy);
+        plan.apply_to_file(&mut file, path)?;
+        Ok(summary)
+    }
+
+    /// Remove SAUCE records from the end of the file at `path`, like
+    /// [`strip_sauce_ex`](crate::strip_sauce_ex) does for a buffer.
+    ///
+    /// The file is truncated with [`File::set_len`]; nothing is written. Files without a
+    /// record are left unchanged.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`].
+    pub fn remove_from_path(path: &Path, mode: StripMode) -> crate::Result<EditSummary> {
+        let mut file = open_rw(path)?;
+        let (offset, tail) = read_tail(&mut file, path)?;
+        let (plan, summary) = plan_remove(&tail, offset, mode);
+        plan.apply_to_file(&mut file, path)?;
+        Ok(summary)
+    }
+
+    /// Crash-safe variant of [`write_to_path`](Self::write_to_path).
+    ///
+    /// The new file is written to a temporary file next to `path`, synced to disk and
+    /// renamed over the original. Permissions and access/modification times of the
+    /// original file are preserved. Unlike the in-place variant this copies the whole
+    /// file content.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`]; the original file is left
+    /// untouched and the temporary file is removed. The one exception is a failure to
+    /// sync the directory after the rename: the new file is in place then, but the
+    /// rename may not survive a crash, so the error (naming the directory) is still
+    /// returned.
+    pub fn write_to_path_atomic(
+        &self,
+        path: &Path,
+        policy: &AttachPolicy,
+    ) -> crate::Result<EditSummary> {
+        let mut file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+        let (offset, tail) = read_tail(&mut file, path)?;
+        let (plan, summary) = plan_attach(&tail, offset, self, policy);
+        plan.apply_to_path_atomic(path)?;
+        Ok(summary)
+    }
+
+    /// Crash-safe variant of [`remove_from_path`](Self::remove_from_path).
+    ///
+    /// See [`write_to_path_atomic`](Self::write_to_path_atomic). Files without a record
+    /// are left unchanged and not rewritten.
+    pub fn remove_from_path_atomic(path: &Path, mode: StripMode) -> crate::Result<EditSummary> {
+        let mut file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+        let (offset, tail) = read_tail(&mut file, path)?;
+        let (plan, summary) = plan_remove(&tail, offset, mode);
+        plan.apply_to_path_atomic(path)?;
+        Ok(summary)
+    }
+
+    /// Attach this record through any seekable handle, like
+    /// [`write_to_path`](Self::write_to_path) does for a file.
+    ///
+    /// Only the tail is read; the new EOF marker and record are written over the old
+    /// record. A generic handle cannot be truncated, so edits that would make the data
+    /// shorter (e.g. a record with fewer comments) are refused before anything is
+    /// written; use [`write_to_path`](Self::write_to_path) for files.
+    ///
+    /// # Errors
+    /// * [`SauceError::EditWouldShrink`] if the edited data is shorter than the original.
+    /// * I/O failures are wrapped in [`SauceError::IoError`].
+    pub fn write_to<W: Read + Write + Seek>(
+        &self,
+        handle: &mut W,
+        policy: &AttachPolicy,
+    ) -> crate::Result<EditSummary> {
+        let (offset, tail) =
+            read_tail_from(handle).map_err(|e| SauceError::io_error("<reader>", e))?;
+        let (plan, summary) = plan_attach(&tail, offset, self, policy);
+        let (keep, appended) = plan.resolve_in_place()?;
+        handle
+            .seek(SeekFrom::Start(keep))
+            .and_then(|_| handle.write_all(&appended))
+            .and_then(|()| handle.flush())
+            .map_err(|e| SauceError::io_error("<writer>", e))?;
+        Ok(summary)
+    }
+}
+
+pub(crate) fn open_rw(path: &Path) -> crate::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(|e| SauceError::io_error(path, e))
+}
+
+/// [`read_tail_from`] for a file, wrapping errors with `path`.
+pub(crate) fn read_tail(file: &mut File, path: &Path) -> crate::Result<(u64, Vec<u8>)> {
+    read_tail_from(file).map_err(|e| SauceError::io_error(path, e))
+}
+
+/// Read enough of the stream's tail to see all stacked records.
+///
+/// Starts with one maximum record length and doubles the window until the content
+/// before the records is at least one record length long (so no record can straddle
+/// the window start) or the whole stream has been read.
+/// Returns the offset of the tail within the stream and the tail bytes.
+pub(crate) fn read_tail_from<R: Read + Seek>(reader: &mut R) -> io::Result<(u64, Vec<u8>)> {
+    let stream_len = reader.seek(SeekFrom::End(0))?;
+    let mut window = MAX_RECORD_LEN as u64;
+    loop {
+        let read_len = window.min(stream_len);
+        let offset = stream_len - read_len;
+        reader.seek(SeekFrom::Start(offset))?;
+        let mut tail = vec![0u8; read_len as usize];
+        reader.read_exact(&mut tail)?;
+
+        if tail_is_complete(offset, &tail) {
+            return Ok((offset, tail));
+        }
+        window *= 2;
+    }
+}
+
+/// Whether `tail` (starting at `offset`) covers all stacked records; see [`read_tail_from`].
+pub(crate) fn tail_is_complete(offset: u64, tail: &[u8]) -> bool {
+    offset == 0 || strip_sauce(tail, StripMode::AllStripFinalEof).len() >= MAX_RECORD_LEN
+}
+
+/// Replace `path` with its first `offset` bytes followed by `new_tail`, via a synced
+/// temporary sibling and a rename.
+pub(crate) fn replace_atomically(
+    path: &Path,
+    original: &mut File,
+    offset: u64,
+    new_tail: &[u8],
+) -> crate::Result<()> {
+    let tmp_path = temp_sibling(path);
+    let result = write_replacement(original, &tmp_path, offset, new_tail)
+        .and_then(|()| fs::rename(&tmp_path, path));
+    if let Err(err) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(SauceError::io_error(path, err));
+    }
+    // The new file is in place now; there is nothing left to clean up
+    sync_parent(path)
+}
+
+fn write_replacement(
+    original: &mut File,
+    tmp_path: &Path,
+    offset: u64,
+    new_tail: &[u8],
+) -> io::Result<()> {
+    let metadata = original.metadata()?;
+    let mut tmp = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(tmp_path)?;
+
+    original.seek(SeekFrom::Start(0))?;
+    io::copy(&mut original.take(offset), &mut tmp)?;
+    tmp.write_all(new_tail)?;
+    tmp.sync_all()?;
+
+    tmp.set_permissions(metadata.permissions())?;
+    let mut times = FileTimes::new();
+    if let Ok(accessed) = metadata.accessed() {
+        times = times.set_accessed(accessed);
+    }
+    if let Ok(modified) = metadata.modified() {
+        times = times.set_modified(modified);
+    }
+    tmp.set_times(times)?;
+    tmp.sync_all()
+}
+
+/// `.name.sauce-tmp-<pid>-<n>-<nanos>` in the directory of `path`.
+///
+/// The per-call counter keeps concurrent edits of the same file apart; the time keeps
+/// leftovers of a crashed earlier process with the same pid from blocking `create_new`.
+fn temp_sibling(path: &Path) -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    path.with_file_name(format!(
+        ".{name}.sauce-tmp-{}-{n}-{nanos}",
+        std::process::id()
+    ))
+}
+
+/// Make the rename durable by syncing the directory entry (not supported on Windows).
+///
+/// Errors are reported with the directory's path.
+fn sync_parent(path: &Path) -> crate::Result<()> {
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+        File::open(dir)
+            .and_then(|d| d.sync_all())
+            .map_err(|e| SauceError::io_error(dir, e))?;
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+    Ok(())
+}
@@ -1,5 +1,8 @@
+#[cfg(feature = "std")]
 use std::path::PathBuf;
 
+use alloc::string::String;
+
 use bstr::BString;
 
 use crate::SauceDataType;
@@ -21,6 +24,7 @@ pub enum SauceError {
     #[error("Unsupported data type for operation: {0:?}")]
     UnsupportedDataType(SauceDataType),
 
+    #[cfg(feature = "std")]
     #[error("IO error reading '{path}': {source}")]
     IoError {
         path: PathBuf,
@@ -28,6 +32,9 @@ pub enum SauceError {
         source: std::io::Error,
     },
 
+    #[error("Write failed: {0}")]
+    WriteFailed(String),
+
     #[error("Comment limit exceeded (255)")]
     CommentLimitExceeded,
 
@@ -48,8 +55,26 @@ pub enum SauceError {
 
     #[error("Missing EOF marker (0x1A) before SAUCE record")]
     MissingEofMarker,
+
+    #[error("Invalid edit plan: {0}")]
+    InvalidEditPlan(String),
+
+    #[error("Edit plan was made for {expected} bytes but the data has {actual} bytes")]
+    StaleEditPlan { expected: u64, actual: u64 },
+
+    #[error(
+        "Edit would shrink the data from {original_len} to {new_len} bytes, but the handle cannot be truncated"
+    )]
+    EditWouldShrink { original_len: u64, new_len: u64 },
+
+    #[error("Content too large for SAUCE file_size: {0} bytes (maximum is 4294967295)")]
+    FileTooLarge(u64),
+
+    #[error("Invalid batch journal: {0}")]
+    InvalidJournal(String),
 }
 
+#[cfg(feature = "std")]
 impl SauceError {
     /// Create an IoError with the given path and source error.
     pub fn io_error(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
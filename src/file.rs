@@ -0,0 +1,146 @@
+//! Whole-file model.
+//!
+//! [`SauceFile`] decomposes a file once into all of its parts:
+//!
+//! ```text
+//! [content] [0x1A]? [older records ...] [0x1A]? [comment block + header] [trailer]
+//!                   └─ stacked ────────┘        └─ record ────────────┘
+//! ```
+//!
+//! Editors can change any part and serialize the file back with
+//! [`SauceFile::to_bytes`], instead of combining [`strip_sauce_ex`](crate::strip_sauce_ex)
+//! and [`SauceRecord::from_bytes`] which both parse the tail.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{SauceFile, SauceRecordBuilder};
+//! use bstr::BString;
+//!
+//! let mut data = b"Content".to_vec();
+//! SauceRecordBuilder::default().build().write(&mut data)?;
+//! data.extend([0u8; 16]); // sector padding
+//!
+//! let mut file = SauceFile::from_bytes(&data)?;
+//! assert_eq!(file.content, b"Content");
+//! assert!(file.eof);
+//! assert_eq!(file.trailer.len(), 16);
+//!
+//! file.record = Some(SauceRecordBuilder::default().title(BString::from("New"))?.build());
+//! file.trailer = &[];
+//! assert_eq!(file.to_bytes().len(), 7 + 1 + 128);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{ByteSink, SauceRecord};
+use alloc::vec::Vec;
+
+/// An older SAUCE record that was left in place when a newer one was appended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackedRecord {
+    /// The parsed record.
+    pub record: SauceRecord,
+    /// Whether an EOF (0x1A) marker directly precedes the record.
+    pub eof: bool,
+}
+
+/// A file decomposed into content, SAUCE records and trailing bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SauceFile<'a> {
+    /// The file content without EOF marker and SAUCE records.
+    pub content: &'a [u8],
+    /// Whether an EOF (0x1A) marker directly precedes `record`.
+    /// Always `false` if the file has no record.
+    pub eof: bool,
+    /// The outermost (newest) SAUCE record.
+    pub record: Option<SauceRecord>,
+    /// Older records below `record`, newest first.
+    pub stacked: Vec<StackedRecord>,
+    /// Bytes after the outermost record, e.g. transfer padding.
+    pub trailer: &'a [u8],
+}
+
+impl<'a> SauceFile<'a> {
+    /// Decompose `data`.
+    ///
+    /// A record at the very end is parsed like [`SauceRecord::stack_from_bytes`]. If the
+    /// data does not end with a record, [`SauceRecord::find_in`] is used to find a record
+    /// followed by a trailer.
+    ///
+    /// # Errors
+    /// Returns the parse error of a malformed outermost record at the end of the data.
+    pub fn from_bytes(data: &'a [u8]) -> crate::Result<Self> {
+        let mut stack = SauceRecord::stack_from_bytes(data)?;
+        if stack.is_empty()
+            && let Some(found) = SauceRecord::find_in(data)
+        {
+            stack = SauceRecord::stack_from_bytes(&data[..found.range.end])?;
+        }
+
+        let Some(outermost) = stack.first() else {
+            return Ok(Self {
+                content: data,
+                eof: false,
+                record: None,
+                stacked: Vec::new(),
+                trailer: &[],
+            });
+        };
+        let trailer = &data[outermost.range.end..];
+
+        let innermost = stack.last().unwrap();
+        let content_end = if innermost.eof_marker {
+            innermost.range.start - 1
+        } else {
+            innermost.range.start
+        };
+
+        let mut stack = stack.into_iter();
+        let outermost = stack.next().unwrap();
+        let stacked = stack
+            .map(|located| StackedRecord {
+                record: located.record,
+                eof: located.eof_marker,
+            })
+            .collect();
+
+        Ok(Self {
+            content: &data[..content_end],
+            eof: outermost.eof_marker,
+            record: Some(outermost.record),
+            stacked,
+            trailer,
+        })
+    }
+
+    /// Serialize the file.
+    ///
+    /// Records are re-encoded (see [`SauceRecord::write`]); use
+    /// [`RawSauceHeader`](crate::RawSauceHeader) if the record bytes must stay untouched.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        let _ = self.write(&mut buf);
+        buf
+    }
+
+    /// Write the file to `writer`.
+    pub fn write<W: ByteSink>(&self, writer: &mut W) -> crate::Result<()> {
+        writer.write_bytes(self.content)?;
+        for stacked in self.stacked.iter().rev() {
+            write_record(writer, &stacked.record, stacked.eof)?;
+        }
+        if let Some(record) = &self.record {
+            write_record(writer, record, self.eof)?;
+        }
+        writer.write_bytes(self.trailer)?;
+        Ok(())
+    }
+}
+
+fn write_record<W: ByteSink>(writer: &mut W, record: &SauceRecord, eof: bool) -> crate::Result<()> {
+    if eof {
+        record.write(writer)
+    } else {
+        record.write_without_eof(writer)
+    }
+}
@@ -46,17 +46,48 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+use alloc::{format, vec::Vec};
+#[cfg(feature = "std")]
+use std::{fs::File, path::Path};
+
 use bstr::BString;
 
+use crate::diagnostics::emit;
+#[cfg(feature = "std")]
+use crate::record::read_last;
 use crate::util::{sauce_pad, trim_spaces, zero_pad, zero_trim};
-use crate::{COMMENT_ID_LEN, COMMENT_LEN, SauceDataType, SauceDate, SauceError, limits};
+use crate::{
+    ByteSink, COMMENT_ID_LEN, COMMENT_LEN, Diagnostic, DiagnosticKind, ParseOptions, SauceDataType,
+    SauceDate, SauceError, ValidationReport, limits,
+};
 
 pub(crate) const HDR_LEN: usize = 128;
-const SAUCE_ID: &[u8; 5] = b"SAUCE";
+pub(crate) const SAUCE_ID: &[u8; 5] = b"SAUCE";
 
 /// Maximum length for the TInfoS field in bytes (zero-padded)
 pub(crate) const TINFO_LEN: usize = 22;
 
+/// Byte positions of the header fields, relative to the start of the 128-byte header.
+pub(crate) mod field {
+    use core::ops::Range;
+
+    pub(crate) const VERSION: Range<usize> = 5..7;
+    pub(crate) const TITLE: Range<usize> = 7..42;
+    pub(crate) const AUTHOR: Range<usize> = 42..62;
+    pub(crate) const GROUP: Range<usize> = 62..82;
+    pub(crate) const DATE: Range<usize> = 82..90;
+    pub(crate) const FILE_SIZE: Range<usize> = 90..94;
+    pub(crate) const DATA_TYPE: usize = 94;
+    pub(crate) const FILE_TYPE: usize = 95;
+    pub(crate) const T_INFO1: Range<usize> = 96..98;
+    pub(crate) const T_INFO2: Range<usize> = 98..100;
+    pub(crate) const T_INFO3: Range<usize> = 100..102;
+    pub(crate) const T_INFO4: Range<usize> = 102..104;
+    pub(crate) const COMMENTS: usize = 104;
+    pub(crate) const T_FLAGS: usize = 105;
+    pub(crate) const T_INFO_S: Range<usize> = 106..128;
+}
+
 #[derive(Clone, Debug, Default, PartialEq)]
 /// Raw SAUCE v00 metadata header (128 bytes).
 ///
@@ -169,7 +200,71 @@ impl SauceHeader {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[must_use]
+    #[allow(clippy::double_must_use)]
     pub fn from_bytes(data: &[u8]) -> crate::Result<Option<Self>> {
+        Self::from_bytes_with_options(data, &ParseOptions::default())
+    }
+
+    /// Deserialize a SAUCE header with explicit parser tolerances.
+    ///
+    /// Behaves like [`from_bytes`](Self::from_bytes), with the version and date checks
+    /// controlled by `options`:
+    ///
+    /// - [`ParseOptions::accept_unknown_version`]: versions other than `"00"` are parsed
+    ///   like v00 instead of failing with [`SauceError::UnsupportedSauceVersion`]
+    /// - [`ParseOptions::require_valid_date`]: a date that is not 8 ASCII digits fails
+    ///   with [`SauceError::UnsupportedSauceDate`] instead of reading as `0000/00/00`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icy_sauce::{header::SauceHeader, ParseOptions, SauceError};
+    ///
+    /// let mut data = Vec::new();
+    /// SauceHeader::default().write(&mut data)?;
+    /// data[82..90].copy_from_slice(b"19xx0101");
+    ///
+    /// assert!(SauceHeader::from_bytes(&data)?.is_some());
+    /// assert!(matches!(
+    ///     SauceHeader::from_bytes_with_options(&data, &ParseOptions::strict()),
+    ///     Err(SauceError::UnsupportedSauceDate(_))
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes_with_options(
+        data: &[u8],
+        options: &ParseOptions,
+    ) -> crate::Result<Option<Self>> {
+        Self::parse(data, options, &mut Vec::new())
+    }
+
+    /// Read only the SAUCE header of the file at `path`.
+    ///
+    /// Just the last 128 bytes are read, which makes this the cheapest way to check many
+    /// files for a record. The comment block is not read; [`comments`](Self::comments)
+    /// holds the announced count.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`]; otherwise as
+    /// [`from_bytes`](Self::from_bytes).
+    #[cfg(feature = "std")]
+    pub fn peek_path(path: &Path) -> crate::Result<Option<Self>> {
+        let mut file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+        let file_len = file
+            .metadata()
+            .map_err(|e| SauceError::io_error(path, e))?
+            .len();
+        let buf = read_last(&mut file, file_len, HDR_LEN, Vec::new())
+            .map_err(|e| SauceError::io_error(path, e))?;
+        Self::from_bytes(&buf)
+    }
+
+    /// Parse the trailing header, recording tolerated deviations in `diagnostics`.
+    pub(crate) fn parse(
+        data: &[u8],
+        options: &ParseOptions,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> crate::Result<Option<Self>> {
         if data.len() < HDR_LEN {
             return Ok(None);
         }
@@ -182,9 +277,20 @@ impl SauceHeader {
         header = &header[5..];
 
         if b"00" != &header[0..2] {
-            return Err(SauceError::UnsupportedSauceVersion(BString::new(
-                header[0..2].to_vec(),
-            )));
+            if !options.accept_unknown_version {
+                return Err(SauceError::UnsupportedSauceVersion(BString::new(
+                    header[0..2].to_vec(),
+                )));
+            }
+            emit(
+                diagnostics,
+                DiagnosticKind::UnknownVersion,
+                header_start + field::VERSION.start,
+                format!(
+                    "Unknown SAUCE version {:?} - parsing as 00",
+                    BString::new(header[0..2].to_vec())
+                ),
+            );
         }
         header = &header[2..];
 
@@ -196,7 +302,26 @@ impl SauceHeader {
         header = &header[limits::MAX_GROUP_LENGTH..];
 
         let (date_bytes, rest) = header.split_at(8);
-        let date = SauceDate::from_bytes(date_bytes).unwrap_or_default();
+        let date = match SauceDate::from_bytes(date_bytes) {
+            Some(date) => date,
+            None if options.require_valid_date => {
+                return Err(SauceError::UnsupportedSauceDate(BString::new(
+                    date_bytes.to_vec(),
+                )));
+            }
+            None => {
+                emit(
+                    diagnostics,
+                    DiagnosticKind::InvalidDate,
+                    header_start + field::DATE.start,
+                    format!(
+                        "Invalid SAUCE date {:?} - using 0000/00/00",
+                        BString::new(date_bytes.to_vec())
+                    ),
+                );
+                SauceDate::default()
+            }
+        };
         let (size_bytes, rest) = rest.split_at(4);
         let file_size = u32::from_le_bytes(size_bytes.try_into().unwrap());
         header = rest;
@@ -263,6 +388,31 @@ impl SauceHeader {
         }
     }
 
+    /// Check this header against the SAUCE v00 specification.
+    ///
+    /// Parsing accepts most deviations from the spec; this reports them as a list of
+    /// typed issues instead. Checks include the date, text fields (length and control
+    /// characters), unknown data/file types, reserved `TFlags` values and type-dependent
+    /// fields that must be 0 for the header's data type.
+    ///
+    /// The file size can only be checked against the actual content; see
+    /// [`SauceRecord::validate_with_content_len`](crate::SauceRecord::validate_with_content_len).
+    ///
+    /// # Example
+    /// ```
+    /// use icy_sauce::{header::SauceHeader, SauceDataType, SauceDate, IssueKind};
+    ///
+    /// let mut h = SauceHeader::default();
+    /// h.date = SauceDate::new(2025, 1, 1);
+    /// h.data_type = SauceDataType::BinaryText; // FileType 0 => width 0
+    /// let report = h.validate();
+    /// assert!(report.has_errors());
+    /// assert_eq!(report.issues()[0].kind, IssueKind::InvalidBinaryTextWidth(0));
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        crate::validation::validate_header(self)
+    }
+
     /// Serialize this SAUCE header to bytes.
     ///
     /// Writes exactly 128 bytes in SAUCE v00 format, with all string fields properly
@@ -302,7 +452,7 @@ impl SauceHeader {
     /// assert_eq!(output.len(), 128);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn write<A: std::io::Write>(&self, writer: &mut A) -> crate::Result<()> {
+    pub fn write<A: ByteSink>(&self, writer: &mut A) -> crate::Result<()> {
         let mut sauce_info = Vec::with_capacity(HDR_LEN);
         sauce_info.extend(SAUCE_ID);
         sauce_info.extend(b"00");
@@ -326,9 +476,7 @@ impl SauceHeader {
         // but catches serialization errors during development.
         debug_assert_eq!(sauce_info.len(), HDR_LEN);
 
-        if let Err(err) = writer.write_all(&sauce_info) {
-            return Err(SauceError::io_error("<writer>", err));
-        }
+        writer.write_bytes(&sauce_info)?;
         Ok(())
     }
 }
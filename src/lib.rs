@@ -1,6 +1,10 @@
-use std::fmt::Display;
+#![cfg_attr(not(feature = "std"), no_std)]
 
-pub type Result<T> = std::result::Result<T, SauceError>;
+extern crate alloc;
+
+use core::fmt::Display;
+
+pub type Result<T> = core::result::Result<T, SauceError>;
 
 mod capabilities;
 pub use capabilities::*;
@@ -20,6 +24,63 @@ pub use date::*;
 mod errors;
 pub use errors::*;
 
+mod validation;
+pub use validation::*;
+
+mod options;
+pub use options::*;
+
+mod diagnostics;
+pub use diagnostics::*;
+
+mod raw;
+pub use raw::*;
+
+mod view;
+pub use view::*;
+
+mod locate;
+pub use locate::*;
+
+mod confidence;
+pub use confidence::*;
+
+mod split;
+pub use split::*;
+
+mod file;
+pub use file::*;
+
+mod attach;
+pub use attach::*;
+
+#[cfg(feature = "std")]
+mod edit;
+
+mod plan;
+pub use plan::*;
+
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "std")]
+pub use batch::*;
+
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::*;
+
+mod sink;
+pub use sink::*;
+
+#[cfg(feature = "tokio")]
+mod async_io;
+
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "mmap")]
+pub use mmap::*;
+
 use crate::header::SauceHeader;
 
 pub mod limits;
@@ -28,6 +89,15 @@ pub mod prelude; // public convenience re-exports
 
 pub(crate) mod util;
 
+// Parsed records are shared across threads (e.g. behind an `Arc`); keep them that way.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SauceRecord>();
+    assert_send_sync::<SauceHeader>();
+    assert_send_sync::<Capabilities>();
+    assert_send_sync::<SauceError>();
+};
+
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum SauceDataType {
@@ -56,7 +126,7 @@ pub enum SauceDataType {
 }
 
 impl Display for SauceDataType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             SauceDataType::None => write!(f, "None"),
             SauceDataType::Character => write!(f, "Character"),
@@ -209,22 +279,16 @@ fn strip_contiguous_records(data: &[u8]) -> Option<usize> {
     let mut cursor = data.len();
     let mut removed_any = false;
 
-    loop {
-        match attempt_single_strip(&data[..cursor]) {
-            Some(end) => {
-                removed_any = true;
-                // Remove the SAUCE record plus one EOF tied to THAT record (if present)
-                let next_cursor = consume_single_eof(data, end);
-                // If no further header exactly at tail we stop and preserve remaining EOFs.
-                if !tail_has_sauce_header(&data[..next_cursor]) {
-                    cursor = next_cursor;
-                    break;
-                }
-                cursor = next_cursor;
-                continue;
-            }
-            None => break,
+    while let Some(end) = attempt_single_strip(&data[..cursor]) {
+        removed_any = true;
+        // Remove the SAUCE record plus one EOF tied to THAT record (if present)
+        let next_cursor = consume_single_eof(data, end);
+        // If no further header exactly at tail we stop and preserve remaining EOFs.
+        if !tail_has_sauce_header(&data[..next_cursor]) {
+            cursor = next_cursor;
+            break;
         }
+        cursor = next_cursor;
     }
     if removed_any { Some(cursor) } else { None }
 }
@@ -358,7 +422,7 @@ pub fn strip_sauce_ex(data: &[u8], mode: StripMode) -> StripResult<'_> {
     let new_end = match mode {
         StripMode::LastStripFinalEof | StripMode::Last => attempt_single_strip(data).map(|end| {
             records = 1;
-            let consumed_end = if mode == StripMode::LastStripFinalEof {
+            if mode == StripMode::LastStripFinalEof {
                 let c = consume_single_eof(data, end);
                 if c != end {
                     eof_count += 1;
@@ -366,27 +430,21 @@ pub fn strip_sauce_ex(data: &[u8], mode: StripMode) -> StripResult<'_> {
                 c
             } else {
                 end
-            };
-            consumed_end
+            }
         }),
         StripMode::All | StripMode::AllStripFinalEof => {
             let mut cursor = data.len();
-            loop {
-                match attempt_single_strip(&data[..cursor]) {
-                    Some(end) => {
-                        records += 1;
-                        let c = consume_single_eof(data, end);
-                        if c != end {
-                            eof_count += 1;
-                        }
-                        if !tail_has_sauce_header(&data[..c]) {
-                            cursor = c;
-                            break;
-                        }
-                        cursor = c;
-                    }
-                    None => break,
+            while let Some(end) = attempt_single_strip(&data[..cursor]) {
+                records += 1;
+                let c = consume_single_eof(data, end);
+                if c != end {
+                    eof_count += 1;
+                }
+                if !tail_has_sauce_header(&data[..c]) {
+                    cursor = c;
+                    break;
                 }
+                cursor = c;
             }
             if records > 0 {
                 if mode == StripMode::AllStripFinalEof {
@@ -34,3 +34,19 @@ pub const DATE_LENGTH: usize = 8;
 
 /// Maximum number of bytes for the font name in binary capabilities.
 pub const MAX_FONT_NAME_LENGTH: usize = 22;
+
+/// Maximum number of bytes a SAUCE record occupies at the end of a file: EOF marker,
+/// "COMNT" tag, 255 comment lines and the 128 byte header.
+///
+/// Reading this many trailing bytes is enough to parse any record.
+pub const MAX_RECORD_LEN: usize = 1 + 5 + MAX_COMMENTS * MAX_COMMENT_LENGTH + 128;
+
+/// Default number of trailing bytes searched for a SAUCE header by
+/// [`SauceRecord::find_in`](crate::SauceRecord::find_in).
+///
+/// Large enough for XMODEM/sector padding and short appended banners.
+pub const DEFAULT_SCAN_WINDOW: usize = 8192;
+
+/// Minimum [`Confidence`](crate::Confidence) score a candidate needs to be returned by
+/// [`SauceRecord::find_in`](crate::SauceRecord::find_in).
+pub const DEFAULT_SCAN_CONFIDENCE: u8 = 50;
@@ -0,0 +1,23 @@
+//! Records found at a known position inside a larger buffer.
+
+use core::ops::Range;
+
+use crate::{Confidence, SauceRecord};
+
+/// A SAUCE record together with its location in the searched data.
+///
+/// Returned by [`SauceRecord::stack_from_bytes`] and [`SauceRecord::find_in`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocatedRecord {
+    /// The parsed record.
+    pub record: SauceRecord,
+    /// Byte range of the comment block and header. The EOF marker is not included.
+    pub range: Range<usize>,
+    /// Whether an EOF (0x1A) marker directly precedes `range`.
+    pub eof_marker: bool,
+    /// Number of bytes after `range` that do not belong to a SAUCE record
+    /// (e.g. transfer padding). Always 0 for records that end the data.
+    pub trailing: usize,
+    /// How likely the record is genuine.
+    pub confidence: Confidence,
+}
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use bstr::BString;
 
 use crate::SauceRecordBuilder;
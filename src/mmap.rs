@@ -0,0 +1,123 @@
+//! Memory-mapped access to large files (requires the `mmap` feature).
+//!
+//! [`MappedSauceFile`] maps a file into memory so the slice based functions — record
+//! parsing, the strip functions, [`SauceRecord::find_in`], [`split_concatenated`] and
+//! [`SauceFile`] — work on multi-gigabyte disk images and archives without reading or
+//! copying them. The operating system only pages in the parts that are accessed.
+//!
+//! The regular `File` based functions stay the default; mapping is only worth it when
+//! a file is scanned more than at its tail.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use icy_sauce::{MappedSauceFile, StripMode};
+//! use std::path::Path;
+//!
+//! let image = MappedSauceFile::open(Path::new("collection.img"))?;
+//! for part in image.split() {
+//!     if let Some(record) = &part.record {
+//!         println!("{}: {} bytes", record.title(), part.content.len());
+//!     }
+//! }
+//! let content = image.strip(StripMode::AllStripFinalEof);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{fs::File, path::Path};
+
+use memmap2::Mmap;
+
+use crate::{
+    LocatedRecord, ParseOptions, SauceError, SauceFile, SauceRecord, SplitPart, StripMode,
+    StripResult, split_concatenated, strip_sauce_ex,
+};
+
+/// A read-only memory mapping of a file.
+///
+/// The file must not be modified or truncated by other processes while it is mapped;
+/// doing so may crash the process (SIGBUS) or change the bytes seen through the slices.
+#[derive(Debug)]
+pub struct MappedSauceFile {
+    map: Mmap,
+}
+
+impl MappedSauceFile {
+    /// Map the file at `path`.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`].
+    pub fn open(path: &Path) -> crate::Result<Self> {
+        let file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+        // SAFETY: the mapping is read-only; concurrent modification of the file is
+        // documented as unsupported on `MappedSauceFile`.
+        let map = unsafe { Mmap::map(&file) }.map_err(|e| SauceError::io_error(path, e))?;
+        Ok(Self { map })
+    }
+
+    /// The whole file.
+    pub fn bytes(&self) -> &[u8] {
+        &self.map
+    }
+
+    /// Parse the SAUCE record at the end of the file, like [`SauceRecord::from_bytes`].
+    ///
+    /// Parsing only looks at the end of the file, so just the pages holding the record
+    /// are read.
+    pub fn record(&self) -> crate::Result<Option<SauceRecord>> {
+        self.record_with_options(&ParseOptions::default())
+    }
+
+    /// Parse the record at the end of the file with explicit parser tolerances.
+    pub fn record_with_options(
+        &self,
+        options: &ParseOptions,
+    ) -> crate::Result<Option<SauceRecord>> {
+        SauceRecord::from_bytes_with_options(&self.map, options)
+    }
+
+    /// The file without SAUCE records, see [`strip_sauce`](crate::strip_sauce).
+    pub fn strip(&self, mode: StripMode) -> &[u8] {
+        self.strip_ex(mode).data
+    }
+
+    /// The file without SAUCE records, with statistics; see [`strip_sauce_ex`].
+    pub fn strip_ex(&self, mode: StripMode) -> StripResult<'_> {
+        strip_sauce_ex(&self.map, mode)
+    }
+
+    /// Search the end of the file for a record, see [`SauceRecord::find_in`].
+    pub fn find(&self) -> Option<LocatedRecord> {
+        SauceRecord::find_in(&self.map)
+    }
+
+    /// Split concatenated files, see [`split_concatenated`].
+    pub fn split(&self) -> Vec<SplitPart<'_>> {
+        split_concatenated(&self.map)
+    }
+
+    /// Decompose the file into content, records and trailer, see [`SauceFile::from_bytes`].
+    pub fn sauce_file(&self) -> crate::Result<SauceFile<'_>> {
+        SauceFile::from_bytes(&self.map)
+    }
+}
+
+impl AsRef<[u8]> for MappedSauceFile {
+    fn as_ref(&self) -> &[u8] {
+        &self.map
+    }
+}
+
+impl SauceRecord {
+    /// Parse a SAUCE record from a memory-mapped file instead of reading its tail.
+    ///
+    /// Same result as [`from_path`](Self::from_path); useful when the file is already in
+    /// the page cache or is scanned further with [`MappedSauceFile`].
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`]. Structural SAUCE issues yield
+    /// specific `SauceError` variants.
+    pub fn from_path_mmap(path: &Path) -> crate::Result<Option<Self>> {
+        MappedSauceFile::open(path)?.record()
+    }
+}
@@ -0,0 +1,117 @@
+//! Parser configuration.
+//!
+//! [`ParseOptions`] controls how tolerant [`SauceRecord::from_bytes_with_options`] and
+//! [`SauceHeader::from_bytes_with_options`] are towards records that deviate from the
+//! SAUCE v00 specification.
+//!
+//! Three presets cover the common use cases:
+//!
+//! | Preset                          | Unknown version | Missing COMNT    | Wrong comment count | Bad date       | Missing EOF |
+//! |---------------------------------|-----------------|------------------|---------------------|----------------|-------------|
+//! | [`ParseOptions::strict`]        | error           | error            | error               | error          | error       |
+//! | [`ParseOptions::default`]       | error           | comments dropped | comments dropped    | `0000/00/00`   | logged      |
+//! | [`ParseOptions::lenient`]       | accepted        | comments read    | block scanned       | `0000/00/00`   | logged      |
+//!
+//! The default preset is what [`SauceRecord::from_bytes`] uses. No preset sets a
+//! [`min_confidence`](ParseOptions::min_confidence); pick a threshold that suits your data.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{ParseOptions, SauceRecord, SauceRecordBuilder, SauceError};
+//!
+//! let mut data = SauceRecordBuilder::default().build().to_bytes();
+//! let version_pos = data.len() - 128 + 5;
+//! data[version_pos..version_pos + 2].copy_from_slice(b"01");
+//!
+//! assert!(matches!(
+//!     SauceRecord::from_bytes_with_options(&data, &ParseOptions::strict()),
+//!     Err(SauceError::UnsupportedSauceVersion(_))
+//! ));
+//! assert!(SauceRecord::from_bytes_with_options(&data, &ParseOptions::lenient())?.is_some());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`SauceRecord::from_bytes_with_options`]: crate::SauceRecord::from_bytes_with_options
+//! [`SauceRecord::from_bytes`]: crate::SauceRecord::from_bytes
+//! [`SauceHeader::from_bytes_with_options`]: crate::header::SauceHeader::from_bytes_with_options
+
+/// Tolerance settings for SAUCE parsing.
+///
+/// Use one of the presets ([`strict`](Self::strict), [`default`](Self::default),
+/// [`lenient`](Self::lenient)) and adjust individual fields as needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Accept headers whose version field is not `"00"`.
+    ///
+    /// When `false` such headers yield [`SauceError::UnsupportedSauceVersion`](crate::SauceError::UnsupportedSauceVersion).
+    pub accept_unknown_version: bool,
+
+    /// Treat a comment block without the `COMNT` ID as an error
+    /// ([`SauceError::InvalidCommentBlock`](crate::SauceError::InvalidCommentBlock)).
+    pub require_comment_id: bool,
+
+    /// Salvage comments from damaged comment blocks.
+    ///
+    /// If the `COMNT` ID is missing the comment lines are read from their expected
+    /// position anyway; if the data is too short to hold the announced comment block
+    /// the comments are dropped instead of failing with
+    /// [`SauceError::InvalidCommentBlock`](crate::SauceError::InvalidCommentBlock).
+    pub recover_comments: bool,
+
+    /// Search for the comment block when the header's comment count does not match it.
+    ///
+    /// If no `COMNT` block with the announced number of lines precedes the header, the
+    /// parser scans backwards (up to 255 lines) for the nearest `COMNT` ID and uses the
+    /// lines found there. The corrected count is stored in the parsed header and the
+    /// discrepancy is reported as
+    /// [`DiagnosticKind::CommentCountMismatch`](crate::DiagnosticKind::CommentCountMismatch).
+    pub scan_for_comments: bool,
+
+    /// Treat a date that is not 8 ASCII digits as an error
+    /// ([`SauceError::UnsupportedSauceDate`](crate::SauceError::UnsupportedSauceDate))
+    /// instead of substituting [`SauceDate::default`](crate::SauceDate::default).
+    pub require_valid_date: bool,
+
+    /// Treat a missing EOF (0x1A) marker before the record as an error
+    /// ([`SauceError::MissingEofMarker`](crate::SauceError::MissingEofMarker)).
+    pub require_eof_marker: bool,
+
+    /// Ignore records whose [`Confidence`](crate::Confidence) score is below this value
+    /// (0..=100), as if the data had no SAUCE record. 0 accepts every record.
+    ///
+    /// Useful to reject text files that happen to end with the `SAUCE00` signature.
+    pub min_confidence: u8,
+}
+
+impl ParseOptions {
+    /// Reject every deviation from the spec that the parser knows about.
+    ///
+    /// Intended for tools that sanity-check uploads before accepting them.
+    pub fn strict() -> Self {
+        Self {
+            accept_unknown_version: false,
+            require_comment_id: true,
+            recover_comments: false,
+            scan_for_comments: false,
+            require_valid_date: true,
+            require_eof_marker: true,
+            min_confidence: 0,
+        }
+    }
+
+    /// Accept as much as possible and keep going.
+    ///
+    /// Intended for tools that salvage metadata from old or damaged files.
+    pub fn lenient() -> Self {
+        Self {
+            accept_unknown_version: true,
+            require_comment_id: false,
+            recover_comments: true,
+            scan_for_comments: true,
+            require_valid_date: false,
+            require_eof_marker: false,
+            min_confidence: 0,
+        }
+    }
+}
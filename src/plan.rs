@@ -0,0 +1,422 @@
+//! Edit plans: reviewable descriptions of SAUCE modifications.
+//!
+//! An [`EditPlan`] lists the exact byte operations needed to attach or remove a SAUCE
+//! record — truncate at an offset, write the EOF marker, write the record bytes —
+//! without performing them. Plans can be printed for review, serialized to a small text
+//! format, parsed back and executed later. Executing a plan checks that the target still
+//! has the length the plan was made for.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{AttachPolicy, EditOp, EditPlan, SauceRecordBuilder};
+//!
+//! let mut data = b"Content".to_vec();
+//! let record = SauceRecordBuilder::default().build();
+//! let plan = EditPlan::for_bytes(&data, &record, &AttachPolicy::default());
+//! assert_eq!(plan.ops[0], EditOp::WriteEof);
+//! println!("{plan}");
+//!
+//! let text = plan.serialize();
+//! let plan = EditPlan::parse(&text)?;
+//! plan.apply_to_vec(&mut data)?;
+//! assert_eq!(data.len(), 7 + 1 + 128);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{Display, Write as _};
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{Seek, SeekFrom, Write},
+    path::Path,
+};
+
+#[cfg(feature = "std")]
+use crate::edit::{open_rw, read_tail};
+use crate::{
+    AttachMode, AttachPolicy, EditSummary, SauceError, SauceRecord, StripMode, strip_sauce,
+    strip_sauce_ex, tail_has_sauce_header,
+    util::{from_hex, to_hex},
+};
+
+/// First line of a serialized plan.
+const PLAN_MAGIC: &str = "SAUCE-EDIT-PLAN 1";
+
+/// A single operation of an [`EditPlan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditOp {
+    /// Cut the data to `at` bytes.
+    Truncate {
+        /// New length.
+        at: u64,
+    },
+    /// Append an EOF (0x1A) marker.
+    WriteEof,
+    /// Append `bytes`.
+    Write {
+        /// The bytes to append.
+        bytes: Vec<u8>,
+    },
+}
+
+impl Display for EditOp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EditOp::Truncate { at } => write!(f, "truncate at {at}"),
+            EditOp::WriteEof => write!(f, "write EOF marker (0x1A)"),
+            EditOp::Write { bytes } => {
+                let preview = &bytes[..bytes.len().min(32)];
+                write!(
+                    f,
+                    "write {} bytes \"{}\"",
+                    bytes.len(),
+                    preview.escape_ascii()
+                )?;
+                if bytes.len() > preview.len() {
+                    write!(f, "...")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Byte operations that turn data of `original_len` bytes into the edited data.
+///
+/// Operations are applied in order; writes append at the current end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditPlan {
+    /// Length of the data the plan was made for.
+    pub original_len: u64,
+    /// The operations to perform.
+    pub ops: Vec<EditOp>,
+}
+
+impl EditPlan {
+    /// Plan attaching `record` to `data` (see [`attach_sauce`](crate::attach_sauce)).
+    pub fn for_bytes(data: &[u8], record: &SauceRecord, policy: &AttachPolicy) -> Self {
+        plan_attach(data, 0, record, policy).0
+    }
+
+    /// Plan attaching `record` to the file at `path`. Only the tail of the file is read.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`].
+    #[cfg(feature = "std")]
+    pub fn for_path(
+        path: &Path,
+        record: &SauceRecord,
+        policy: &AttachPolicy,
+    ) -> crate::Result<Self> {
+        let mut file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+        let (offset, tail) = read_tail(&mut file, path)?;
+        Ok(plan_attach(&tail, offset, record, policy).0)
+    }
+
+    /// Plan removing records from `data` (see [`strip_sauce_ex`]).
+    pub fn remove_for_bytes(data: &[u8], mode: StripMode) -> Self {
+        plan_remove(data, 0, mode).0
+    }
+
+    /// Plan removing records from the file at `path`. Only the tail of the file is read.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`].
+    #[cfg(feature = "std")]
+    pub fn remove_for_path(path: &Path, mode: StripMode) -> crate::Result<Self> {
+        let mut file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+        let (offset, tail) = read_tail(&mut file, path)?;
+        Ok(plan_remove(&tail, offset, mode).0)
+    }
+
+    /// Returns `true` if the plan does not change anything.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Length of the data after applying the plan.
+    pub fn new_len(&self) -> u64 {
+        self.ops.iter().fold(self.original_len, |len, op| match op {
+            EditOp::Truncate { at } => len.min(*at),
+            EditOp::WriteEof => len + 1,
+            EditOp::Write { bytes } => len + bytes.len() as u64,
+        })
+    }
+
+    /// Apply the plan to `data`.
+    ///
+    /// # Errors
+    /// [`SauceError::StaleEditPlan`] if `data` does not have the plan's original length,
+    /// [`SauceError::InvalidEditPlan`] if an operation truncates beyond the current end.
+    pub fn apply_to_vec(&self, data: &mut Vec<u8>) -> crate::Result<()> {
+        self.check_len(data.len() as u64)?;
+        let (keep, appended) = self.resolve()?;
+        data.truncate(keep as usize);
+        data.extend(appended);
+        Ok(())
+    }
+
+    /// Apply the plan to the file at `path` in place.
+    ///
+    /// The file is truncated with [`File::set_len`] and the new bytes are appended;
+    /// content before the truncation point is not touched.
+    ///
+    /// # Errors
+    /// As [`apply_to_vec`](Self::apply_to_vec); I/O failures are wrapped in
+    /// [`SauceError::IoError`].
+    #[cfg(feature = "std")]
+    pub fn apply_to_path(&self, path: &Path) -> crate::Result<()> {
+        let mut file = open_rw(path)?;
+        self.apply_to_file(&mut file, path)
+    }
+
+    /// Crash-safe variant of [`apply_to_path`](Self::apply_to_path), see
+    /// [`SauceRecord::write_to_path_atomic`].
+    #[cfg(feature = "std")]
+    pub fn apply_to_path_atomic(&self, path: &Path) -> crate::Result<()> {
+        let mut file = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
+        let len = file
+            .metadata()
+            .map_err(|e| SauceError::io_error(path, e))?
+            .len();
+        self.check_len(len)?;
+        if self.is_empty() {
+            return Ok(());
+        }
+        let (keep, appended) = self.resolve()?;
+        crate::edit::replace_atomically(path, &mut file, keep, &appended)
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn apply_to_file(&self, file: &mut File, path: &Path) -> crate::Result<()> {
+        let len = file
+            .metadata()
+            .map_err(|e| SauceError::io_error(path, e))?
+            .len();
+        self.check_len(len)?;
+        if self.is_empty() {
+            return Ok(());
+        }
+        let (keep, appended) = self.resolve()?;
+        file.set_len(keep)
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.seek(SeekFrom::Start(keep))
+            .map_err(|e| SauceError::io_error(path, e))?;
+        file.write_all(&appended)
+            .map_err(|e| SauceError::io_error(path, e))?;
+        Ok(())
+    }
+
+    pub(crate) fn check_len(&self, actual: u64) -> crate::Result<()> {
+        if actual != self.original_len {
+            return Err(SauceError::StaleEditPlan {
+                expected: self.original_len,
+                actual,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reduce the operations to "keep this many original bytes, then append these".
+    pub(crate) fn resolve(&self) -> crate::Result<(u64, Vec<u8>)> {
+        let mut keep = self.original_len;
+        let mut appended = Vec::new();
+        for op in &self.ops {
+            match op {
+                EditOp::Truncate { at } => {
+                    if *at <= keep {
+                        keep = *at;
+                        appended.clear();
+                    } else if *at - keep <= appended.len() as u64 {
+                        appended.truncate((*at - keep) as usize);
+                    } else {
+                        return Err(SauceError::InvalidEditPlan(format!(
+                            "truncate at {at} beyond end"
+                        )));
+                    }
+                }
+                EditOp::WriteEof => appended.push(0x1A),
+                EditOp::Write { bytes } => appended.extend(bytes),
+            }
+        }
+        Ok((keep, appended))
+    }
+
+    /// [`resolve`](Self::resolve) for handles that can only be overwritten, not
+    /// truncated: fails if the edited data would be shorter than the original.
+    #[cfg(feature = "std")]
+    pub(crate) fn resolve_in_place(&self) -> crate::Result<(u64, Vec<u8>)> {
+        let new_len = self.new_len();
+        if new_len < self.original_len {
+            return Err(SauceError::EditWouldShrink {
+                original_len: self.original_len,
+                new_len,
+            });
+        }
+        self.resolve()
+    }
+
+    /// Serialize the plan to a line based text format.
+    ///
+    /// ```text
+    /// SAUCE-EDIT-PLAN 1
+    /// original-len 1234
+    /// truncate 1000
+    /// write-eof
+    /// write 5341554345...
+    /// ```
+    pub fn serialize(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "{PLAN_MAGIC}");
+        let _ = writeln!(out, "original-len {}", self.original_len);
+        for op in &self.ops {
+            match op {
+                EditOp::Truncate { at } => {
+                    let _ = writeln!(out, "truncate {at}");
+                }
+                EditOp::WriteEof => out.push_str("write-eof\n"),
+                EditOp::Write { bytes } => {
+                    let _ = writeln!(out, "write {}", to_hex(bytes));
+                }
+            }
+        }
+        out
+    }
+
+    /// Parse a plan produced by [`serialize`](Self::serialize).
+    ///
+    /// # Errors
+    /// [`SauceError::InvalidEditPlan`] describing the first malformed line.
+    pub fn parse(text: &str) -> crate::Result<Self> {
+        let invalid = |msg: String| SauceError::InvalidEditPlan(msg);
+        let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+        if lines.next().map(str::trim) != Some(PLAN_MAGIC) {
+            return Err(invalid("missing plan header".into()));
+        }
+        let original_len = match lines.next().and_then(|l| l.trim().split_once(' ')) {
+            Some(("original-len", len)) => parse_u64(len)?,
+            _ => return Err(invalid("missing original-len".into())),
+        };
+
+        let mut ops = Vec::new();
+        for line in lines {
+            let line = line.trim();
+            let (op, arg) = line.split_once(' ').unwrap_or((line, ""));
+            ops.push(match op {
+                "truncate" => EditOp::Truncate {
+                    at: parse_u64(arg)?,
+                },
+                "write-eof" => EditOp::WriteEof,
+                "write" => EditOp::Write {
+                    bytes: parse_hex(arg)?,
+                },
+                _ => return Err(invalid(format!("unknown operation '{op}'"))),
+            });
+        }
+        Ok(Self { original_len, ops })
+    }
+}
+
+impl Display for EditPlan {
+    /// Human readable listing of the operations, one per line.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "original length: {} bytes", self.original_len)?;
+        if self.ops.is_empty() {
+            writeln!(f, "no changes")?;
+        }
+        for op in &self.ops {
+            writeln!(f, "{op}")?;
+        }
+        write!(f, "new length: {} bytes", self.new_len())
+    }
+}
+
+fn parse_u64(text: &str) -> crate::Result<u64> {
+    text.trim()
+        .parse()
+        .map_err(|_| SauceError::InvalidEditPlan(format!("invalid number '{text}'")))
+}
+
+fn parse_hex(text: &str) -> crate::Result<Vec<u8>> {
+    from_hex(text).map_err(SauceError::InvalidEditPlan)
+}
+
+/// Plan attaching `record` to `tail`, the last bytes of a file starting at `offset`.
+pub(crate) fn plan_attach(
+    tail: &[u8],
+    offset: u64,
+    record: &SauceRecord,
+    policy: &AttachPolicy,
+) -> (EditPlan, EditSummary) {
+    let strip_mode = match policy.mode {
+        AttachMode::Replace => Some(StripMode::LastStripFinalEof),
+        AttachMode::ReplaceAll => Some(StripMode::AllStripFinalEof),
+        AttachMode::Stack => None,
+    };
+    let mut kept = tail;
+    let (mut records_removed, mut eof_bytes_removed) = (0, 0);
+    if let Some(mode) = strip_mode {
+        let result = strip_sauce_ex(kept, mode);
+        records_removed = result.records_removed;
+        eof_bytes_removed = result.eof_bytes_removed;
+        kept = result.data;
+    }
+    // Never eat into the last byte of a record that stays in place
+    if policy.normalize_eof && !tail_has_sauce_header(kept) {
+        while let [rest @ .., 0x1A] = kept {
+            kept = rest;
+            eof_bytes_removed += 1;
+        }
+    }
+
+    let content_len = offset + strip_sauce(kept, StripMode::AllStripFinalEof).len() as u64;
+    let mut file_size = None;
+    let mut record = record.clone();
+    if policy.fill_file_size {
+        let size = u32::try_from(content_len).unwrap_or(0);
+        record.header.file_size = size;
+        file_size = Some(size);
+    }
+
+    let original_len = offset + tail.len() as u64;
+    let keep = offset + kept.len() as u64;
+    let mut ops = Vec::new();
+    if keep < original_len {
+        ops.push(EditOp::Truncate { at: keep });
+    }
+    ops.push(EditOp::WriteEof);
+    ops.push(EditOp::Write {
+        bytes: record.to_bytes_without_eof(),
+    });
+    let plan = EditPlan { original_len, ops };
+
+    let summary = EditSummary {
+        records_removed,
+        eof_bytes_removed,
+        content_len,
+        new_len: plan.new_len(),
+        file_size,
+    };
+    (plan, summary)
+}
+
+/// Plan removing records from `tail`, the last bytes of a file starting at `offset`.
+pub(crate) fn plan_remove(tail: &[u8], offset: u64, mode: StripMode) -> (EditPlan, EditSummary) {
+    let result = strip_sauce_ex(tail, mode);
+    let original_len = offset + tail.len() as u64;
+    let keep = offset + result.data.len() as u64;
+    let mut ops = Vec::new();
+    if keep < original_len {
+        ops.push(EditOp::Truncate { at: keep });
+    }
+    let summary = EditSummary {
+        records_removed: result.records_removed,
+        eof_bytes_removed: result.eof_bytes_removed,
+        content_len: offset + strip_sauce(result.data, StripMode::AllStripFinalEof).len() as u64,
+        new_len: keep,
+        file_size: None,
+    };
+    (EditPlan { original_len, ops }, summary)
+}
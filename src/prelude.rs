@@ -29,11 +29,25 @@
 //! ## Re-exported Items
 //!
 //! - Core record & builder: [`SauceRecord`], [`SauceRecordBuilder`]
+//! - Whole-file model: [`SauceFile`], [`StackedRecord`]
 //! - Date handling: [`SauceDate`]
 //! - Data type enum & error: [`SauceDataType`], [`SauceError`]
 //! - Result alias: [`Result`]
+//! - Parser configuration: [`ParseOptions`]
+//! - Parse diagnostics: [`Diagnostic`], [`DiagnosticKind`]
+//! - Detection confidence: [`Confidence`], [`ConfidenceCheck`]
 //! - Metadata: [`MetaData`]
+//! - Byte-exact editing: [`RawSauceHeader`]
+//! - Zero-copy views: [`SauceRecordRef`], [`SauceHeaderRef`]
+//! - Validation: [`SauceRecord::validate`], [`ValidationReport`], [`ValidationIssue`], [`IssueKind`], [`Severity`]
+//! - Splitting concatenated files: [`split_concatenated`], [`SplitPart`]
+//! - Attaching records: [`attach_sauce`], [`AttachPolicy`], [`AttachMode`], [`EditSummary`]
+//! - Edit plans: [`EditPlan`], [`EditOp`]
+//! - Output sinks: [`ByteSink`], [`IoSink`] (`std` feature)
+//! - Batch edits with undo (`std` feature): [`BatchEdit`], [`Journal`], [`JournalEntry`]
+//! - Non-seekable streams (`std` feature): [`detect_stream`], [`StreamDetector`], [`StreamResult`], [`SauceStrippingReader`], [`SauceAppendingWriter`]
 //! - Strip functions: [`strip_sauce`], [`strip_sauce_mut`], [`strip_sauce_ex`], [`StripMode`], [`StripResult`]
+//! - Memory-mapped files (`mmap` feature): `MappedSauceFile`
 //! - Unified capabilities enum: [`Capabilities`]
 //! - Capability structs & format enums for all categories
 //!   - Character: [`CharacterCapabilities`], [`CharacterFormat`], [`LetterSpacing`], [`AspectRatio`]
@@ -52,6 +66,9 @@ pub use crate::{
     ArchiveCapabilities,
     ArchiveFormat,
     AspectRatio,
+    // Attaching
+    AttachMode,
+    AttachPolicy,
     // Audio
     AudioCapabilities,
     AudioFormat,
@@ -61,31 +78,79 @@ pub use crate::{
     // Bitmap
     BitmapCapabilities,
     BitmapFormat,
+    // Output
+    ByteSink,
     // Unified enum
     Capabilities,
     // Character
     CharacterCapabilities,
     CharacterFormat,
+    // Confidence
+    Confidence,
+    ConfidenceCheck,
+    // Diagnostics
+    Diagnostic,
+    DiagnosticKind,
+    // Edit plans
+    EditOp,
+    EditPlan,
+    EditSummary,
     // Executable
     ExecutableCapabilities,
+    // Validation
+    IssueKind,
     LetterSpacing,
     // Metadata
     MetaData,
+    // Parsing
+    ParseOptions,
+    // Byte-exact editing
+    RawSauceHeader,
     Result,
     SauceDataType,
     SauceDate,
     // Error
     SauceError,
     // Core types
+    SauceFile,
+    SauceHeaderRef,
     SauceRecord,
     SauceRecordBuilder,
+    SauceRecordRef,
+    Severity,
+    // Splitting
+    SplitPart,
+    StackedRecord,
     // Strip functions & types
     StripMode,
     StripResult,
+    ValidationIssue,
+    ValidationReport,
     // Vector
     VectorCapabilities,
     VectorFormat,
+    attach_sauce,
+    split_concatenated,
     strip_sauce,
     strip_sauce_ex,
     strip_sauce_mut,
 };
+
+#[cfg(feature = "std")]
+pub use crate::{
+    // Batch edits
+    BatchEdit,
+    // Output
+    IoSink,
+    Journal,
+    JournalEntry,
+    SauceAppendingWriter,
+    SauceStrippingReader,
+    // Streams
+    StreamDetector,
+    StreamResult,
+    detect_stream,
+};
+
+#[cfg(feature = "mmap")]
+pub use crate::MappedSauceFile;
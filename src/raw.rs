@@ -0,0 +1,194 @@
+//! Byte-exact SAUCE records.
+//!
+//! [`SauceRecord`] normalizes the data it parses: trailing NULs are trimmed from
+//! space-padded fields, malformed dates become `0000/00/00` and unknown versions are
+//! rewritten as `"00"`. Writing such a record back therefore does not necessarily
+//! reproduce the original bytes.
+//!
+//! [`RawSauceHeader`] keeps the original 128 header bytes and the comment block as they
+//! were found. [`RawSauceHeader::apply`] re-encodes only the fields that differ between
+//! the stored bytes and an edited [`SauceRecord`], so archival tools can change a single
+//! field of a historical file and leave every other byte untouched.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{RawSauceHeader, SauceRecordBuilder};
+//! use bstr::BString;
+//!
+//! let mut data = b"Content".to_vec();
+//! SauceRecordBuilder::default()
+//!     .title(BString::from("Old"))?
+//!     .build()
+//!     .write(&mut data)?;
+//! // A NUL padded author, as written by some old tools
+//! let author_pos = data.len() - 128 + 42;
+//! data[author_pos..author_pos + 20].copy_from_slice(&[0; 20]);
+//!
+//! let mut raw = RawSauceHeader::from_bytes(&data).unwrap();
+//! let mut builder = raw.to_record().to_builder();
+//! builder = builder.title(BString::from("New"))?;
+//! raw.apply(&builder.build());
+//!
+//! let bytes = raw.to_bytes_without_eof();
+//! assert_eq!(&bytes[7..10], b"New");
+//! assert_eq!(&bytes[42..62], &[0; 20]); // untouched
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{
+    ByteSink, COMMENT_ID, COMMENT_ID_LEN, COMMENT_LEN, ParseOptions, SauceRecord,
+    header::{HDR_LEN, SAUCE_ID, field},
+    util::sauce_pad,
+};
+
+/// A SAUCE header and comment block kept exactly as stored.
+///
+/// The EOF marker is not part of the raw representation; [`write`](Self::write) emits a
+/// fresh one and [`write_without_eof`](Self::write_without_eof) omits it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawSauceHeader {
+    header: [u8; HDR_LEN],
+    comment_block: Vec<u8>,
+}
+
+impl RawSauceHeader {
+    /// Capture the trailing SAUCE header and comment block of `data`.
+    ///
+    /// Returns `None` if `data` does not end with a SAUCE header. If the data is too short
+    /// to hold the announced comment block the block is left empty; the comment count byte
+    /// is kept as is.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < HDR_LEN {
+            return None;
+        }
+        let header_start = data.len() - HDR_LEN;
+        let header: [u8; HDR_LEN] = data[header_start..].try_into().unwrap();
+        if &header[..SAUCE_ID.len()] != SAUCE_ID {
+            return None;
+        }
+
+        let comments = header[field::COMMENTS] as usize;
+        let block_len = COMMENT_ID_LEN + comments * COMMENT_LEN;
+        let comment_block = if comments > 0 && header_start >= block_len {
+            data[header_start - block_len..header_start].to_vec()
+        } else {
+            Vec::new()
+        };
+
+        Some(Self {
+            header,
+            comment_block,
+        })
+    }
+
+    /// The stored 128 header bytes.
+    pub fn header_bytes(&self) -> &[u8; HDR_LEN] {
+        &self.header
+    }
+
+    /// The stored comment block (`COMNT` ID plus comment lines), empty if there is none.
+    pub fn comment_block(&self) -> &[u8] {
+        &self.comment_block
+    }
+
+    /// Decode the stored bytes into a [`SauceRecord`].
+    ///
+    /// Decoding uses [`ParseOptions::lenient`], so unknown versions, malformed dates and
+    /// comment blocks without `COMNT` ID never fail. [`apply`](Self::apply) compares against
+    /// this decoding to find the modified fields.
+    pub fn to_record(&self) -> SauceRecord {
+        SauceRecord::from_bytes_with_options(&self.to_bytes_without_eof(), &ParseOptions::lenient())
+            .ok()
+            .flatten()
+            .expect("raw header starts with the SAUCE ID")
+    }
+
+    /// Re-encode the fields of `record` that differ from the stored bytes.
+    ///
+    /// Unmodified fields keep their original bytes, including non standard padding,
+    /// malformed dates and the version field. The comment block is only rewritten
+    /// (together with the comment count) if the comment lines changed.
+    ///
+    /// Since malformed dates decode as [`SauceDate::default`](crate::SauceDate::default),
+    /// explicitly setting that date on such a record is not detected as a change.
+    pub fn apply(&mut self, record: &SauceRecord) {
+        let original = self.to_record();
+        let old = original.header();
+        let new = record.header();
+
+        let mut encoded = Vec::with_capacity(HDR_LEN);
+        let _ = new.write(&mut encoded);
+
+        let fields: [(Range<usize>, bool); 13] = [
+            (field::TITLE, old.title != new.title),
+            (field::AUTHOR, old.author != new.author),
+            (field::GROUP, old.group != new.group),
+            (field::DATE, old.date != new.date),
+            (field::FILE_SIZE, old.file_size != new.file_size),
+            (
+                field::DATA_TYPE..field::DATA_TYPE + 1,
+                old.data_type != new.data_type,
+            ),
+            (
+                field::FILE_TYPE..field::FILE_TYPE + 1,
+                old.file_type != new.file_type,
+            ),
+            (field::T_INFO1, old.t_info1 != new.t_info1),
+            (field::T_INFO2, old.t_info2 != new.t_info2),
+            (field::T_INFO3, old.t_info3 != new.t_info3),
+            (field::T_INFO4, old.t_info4 != new.t_info4),
+            (
+                field::T_FLAGS..field::T_FLAGS + 1,
+                old.t_flags != new.t_flags,
+            ),
+            (field::T_INFO_S, old.t_info_s != new.t_info_s),
+        ];
+        for (range, changed) in fields {
+            if changed {
+                self.header[range.clone()].copy_from_slice(&encoded[range]);
+            }
+        }
+
+        if original.comments() != record.comments() {
+            self.header[field::COMMENTS] = record.comments().len() as u8;
+            self.comment_block.clear();
+            if !record.comments().is_empty() {
+                self.comment_block.extend(&COMMENT_ID);
+                for comment in record.comments() {
+                    self.comment_block.extend(sauce_pad(comment, COMMENT_LEN));
+                }
+            }
+        }
+    }
+
+    /// Serialize the stored bytes with a leading EOF marker.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(1 + self.comment_block.len() + HDR_LEN);
+        let _ = self.write(&mut buf);
+        buf
+    }
+
+    /// Serialize the stored bytes without EOF marker.
+    pub fn to_bytes_without_eof(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.comment_block.len() + HDR_LEN);
+        let _ = self.write_without_eof(&mut buf);
+        buf
+    }
+
+    /// Write the stored bytes with a leading EOF marker.
+    pub fn write<W: ByteSink>(&self, writer: &mut W) -> crate::Result<()> {
+        writer.write_bytes(&[0x1A])?;
+        self.write_without_eof(writer)
+    }
+
+    /// Write the stored bytes without EOF marker.
+    pub fn write_without_eof<W: ByteSink>(&self, writer: &mut W) -> crate::Result<()> {
+        writer.write_bytes(&self.comment_block)?;
+        writer.write_bytes(&self.header)?;
+        Ok(())
+    }
+}
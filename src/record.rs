@@ -33,22 +33,28 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+use alloc::{format, vec::Vec};
+#[cfg(feature = "std")]
 use std::{
-    cell::OnceCell,
     fs::File,
-    io::{Read, Seek, SeekFrom, Write},
+    io::{Read, Seek, SeekFrom},
 };
 
 use bstr::BString;
 
+#[cfg(feature = "std")]
+use crate::header::{SAUCE_ID, field};
 use crate::{
-    Capabilities, MetaData, SauceDataType, SauceDate, SauceError, SauceRecordBuilder,
-    VectorCapabilities,
+    ByteSink, Capabilities, Confidence, Diagnostic, DiagnosticKind, LocatedRecord, MetaData,
+    ParseOptions, RawSauceHeader, SauceDataType, SauceDate, SauceError, SauceRecordBuilder,
+    ValidationReport, VectorCapabilities,
     archive::ArchiveCapabilities,
     audio::AudioCapabilities,
     binary::BinaryCapabilities,
     bitmap::BitmapCapabilities,
     character::CharacterCapabilities,
+    confidence,
+    diagnostics::emit,
     executable::ExecutableCapabilities,
     header::{HDR_LEN, SauceHeader},
     util::{sauce_pad, trim_spaces},
@@ -56,34 +62,85 @@ use crate::{
 
 pub(crate) const COMMENT_LEN: usize = 64;
 pub(crate) const COMMENT_ID_LEN: usize = 5;
-const COMMENT_ID: [u8; COMMENT_ID_LEN] = *b"COMNT";
+pub(crate) const COMMENT_ID: [u8; COMMENT_ID_LEN] = *b"COMNT";
+
+/// "SAUCE" ID followed by the only known version.
+const SAUCE_SIGNATURE: &[u8; 7] = b"SAUCE00";
+
+/// Returns true if a `COMNT` block with `count` lines directly precedes the trailing header.
+fn has_comment_block(data: &[u8], count: u8) -> bool {
+    let expected = HDR_LEN + COMMENT_ID_LEN + count as usize * COMMENT_LEN;
+    count > 0 && data.len() >= expected && data[data.len() - expected..].starts_with(&COMMENT_ID)
+}
+
+/// Search backwards from the trailing header for a `COMNT` ID at a line boundary.
+/// Returns the number of comment lines of the nearest block found.
+fn scan_comment_block(data: &[u8]) -> Option<usize> {
+    (1..=crate::limits::MAX_COMMENTS).find(|&count| has_comment_block(data, count as u8))
+}
 
 /// SAUCE information.
 /// This is the main structure for SAUCE.
 ///
 /// SAUCE metadata consits of a header and optional comments.
-#[derive(PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct SauceRecord {
     pub(crate) header: SauceHeader,
 
     /// Up to 255 comments, each 64 bytes long max.
     pub(crate) comments: Vec<BString>,
 
-    pub(crate) cached_caps: OnceCell<Option<Capabilities>>,
+    /// Capabilities decoded from `header` when the record is built. The header can't
+    /// change afterwards, so this never goes stale and needs no interior mutability.
+    pub(crate) caps: Option<Capabilities>,
 }
 
-// Custom Clone impl that resets cache
-impl Clone for SauceRecord {
-    fn clone(&self) -> Self {
-        Self {
-            header: self.header.clone(),
-            comments: self.comments.clone(),
-            cached_caps: OnceCell::new(), // fresh cache
-        }
+// Custom Debug impl that leaves out the decoded capabilities
+impl core::fmt::Debug for SauceRecord {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SauceRecord")
+            .field("header", &self.header)
+            .field("comments", &self.comments)
+            .finish()
     }
 }
 
 impl SauceRecord {
+    /// Assemble a record, decoding its capabilities from `header`.
+    pub(crate) fn new(header: SauceHeader, comments: Vec<BString>) -> Self {
+        let caps = match header.data_type {
+            SauceDataType::Character => CharacterCapabilities::try_from(&header)
+                .ok()
+                .map(Capabilities::Character),
+            SauceDataType::BinaryText | SauceDataType::XBin => {
+                BinaryCapabilities::try_from(&header)
+                    .ok()
+                    .map(Capabilities::Binary)
+            }
+            SauceDataType::Bitmap => BitmapCapabilities::try_from(&header)
+                .ok()
+                .map(Capabilities::Bitmap),
+            SauceDataType::Vector => VectorCapabilities::try_from(&header)
+                .ok()
+                .map(Capabilities::Vector),
+            SauceDataType::Audio => AudioCapabilities::try_from(&header)
+                .ok()
+                .map(Capabilities::Audio),
+            SauceDataType::Archive => ArchiveCapabilities::try_from(&header)
+                .ok()
+                .map(Capabilities::Archive),
+            SauceDataType::Executable => ExecutableCapabilities::try_from(&header)
+                .ok()
+                .map(Capabilities::Executable),
+            _ => None,
+        };
+        Self {
+            header,
+            comments,
+            caps,
+        }
+    }
+
     /// Attempt to parse a SAUCE record from a complete file buffer.
     ///
     /// This function expects the slice `data` to contain the entire file contents,
@@ -99,64 +156,366 @@ impl SauceRecord {
     /// The SAUCE specification tolerates certain minor deviations; for example a missing
     /// `COMNT` marker or missing EOF character are logged as warnings (via the `log` crate)
     /// but do not abort parsing. Severe structural problems (length mismatches) yield an error.
+    /// Use [`from_bytes_with_diagnostics`](Self::from_bytes_with_diagnostics) to receive
+    /// these warnings as values instead.
     ///
     /// # Performance
     /// Parsing is O(1) relative to the number of comments (bounded to 255) and otherwise
     /// proportional to the fixed header size. No heap allocations are performed except
     /// for copying comment lines and the header's owned strings.
     #[must_use]
+    #[allow(clippy::double_must_use)]
     pub fn from_bytes(data: &[u8]) -> crate::Result<Option<Self>> {
-        let Some(header) = SauceHeader::from_bytes(data)? else {
+        Self::from_bytes_with_options(data, &ParseOptions::default())
+    }
+
+    /// Parse a SAUCE record from a complete file buffer with explicit parser tolerances.
+    ///
+    /// [`from_bytes`](Self::from_bytes) is equivalent to calling this with
+    /// [`ParseOptions::default`]. Use [`ParseOptions::strict`] to turn every tolerated
+    /// deviation into an error, or [`ParseOptions::lenient`] to salvage as much as possible.
+    ///
+    /// # Errors
+    /// In addition to the errors of [`from_bytes`](Self::from_bytes):
+    /// * [`SauceError::UnsupportedSauceDate`] if `require_valid_date` is set and the date is malformed.
+    /// * [`SauceError::InvalidCommentBlock`] if `require_comment_id` is set and the `COMNT` ID is missing.
+    /// * [`SauceError::MissingEofMarker`] if `require_eof_marker` is set and no 0x1A precedes the record.
+    ///
+    /// # Example
+    /// ```
+    /// use icy_sauce::{ParseOptions, SauceRecord, SauceRecordBuilder, SauceError};
+    ///
+    /// let sauce = SauceRecordBuilder::default().build();
+    /// let data = sauce.to_bytes_without_eof();
+    ///
+    /// assert!(SauceRecord::from_bytes(&data)?.is_some());
+    /// assert!(matches!(
+    ///     SauceRecord::from_bytes_with_options(&data, &ParseOptions::strict()),
+    ///     Err(SauceError::MissingEofMarker)
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes_with_options(
+        data: &[u8],
+        options: &ParseOptions,
+    ) -> crate::Result<Option<Self>> {
+        Self::parse(data, options, &mut Vec::new())
+    }
+
+    /// Parse a SAUCE record and collect every tolerated deviation as a [`Diagnostic`].
+    ///
+    /// Behaves exactly like [`from_bytes_with_options`](Self::from_bytes_with_options)
+    /// (including the `log` warnings), but additionally returns the problems found so
+    /// callers can associate them with the file being parsed. Deviations that the
+    /// options turn into errors are returned as `Err` and are not part of the list.
+    ///
+    /// Diagnostic offsets are relative to the start of `data`.
+    ///
+    /// # Example
+    /// ```
+    /// use icy_sauce::{DiagnosticKind, ParseOptions, SauceRecord, SauceRecordBuilder};
+    ///
+    /// let mut data = b"Content".to_vec();
+    /// SauceRecordBuilder::default().build().write(&mut data)?;
+    ///
+    /// let (record, diagnostics) =
+    ///     SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::default())?;
+    /// assert!(record.is_some());
+    /// assert!(diagnostics.is_empty());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_bytes_with_diagnostics(
+        data: &[u8],
+        options: &ParseOptions,
+    ) -> crate::Result<(Option<Self>, Vec<Diagnostic>)> {
+        let mut diagnostics = Vec::new();
+        let record = Self::parse(data, options, &mut diagnostics)?;
+        Ok((record, diagnostics))
+    }
+
+    pub(crate) fn parse(
+        data: &[u8],
+        options: &ParseOptions,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> crate::Result<Option<Self>> {
+        Self::parse_at(data, 0, options, diagnostics)
+    }
+
+    /// Parse the record at the end of a tail window that starts `offset` bytes into the
+    /// stream, as read by `from_path`, `from_reader` and their async variants.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_window(
+        window: &[u8],
+        offset: u64,
+        options: &ParseOptions,
+    ) -> crate::Result<Option<Self>> {
+        Self::parse_at(window, offset, options, &mut Vec::new())
+    }
+
+    /// [`parse`](Self::parse) for data starting `offset` bytes into the stream; the
+    /// offset only matters for the `FileSize` check of `min_confidence`.
+    fn parse_at(
+        data: &[u8],
+        offset: u64,
+        options: &ParseOptions,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> crate::Result<Option<Self>> {
+        let Some(mut header) = SauceHeader::parse(data, options, diagnostics)? else {
             return Ok(None);
         };
 
+        if options.scan_for_comments
+            && !has_comment_block(data, header.comments)
+            && let Some(found) = scan_comment_block(data)
+        {
+            emit(
+                diagnostics,
+                DiagnosticKind::CommentCountMismatch,
+                data.len() - HDR_LEN - COMMENT_ID_LEN - found * COMMENT_LEN,
+                format!(
+                    "SAUCE header announces {} comment lines but the comment block has {} - using the block",
+                    header.comments, found
+                ),
+            );
+            header.comments = found as u8;
+        }
+
         let mut comments = Vec::new();
         if header.comments > 0 {
             let expected = HDR_LEN + header.comments as usize * COMMENT_LEN + COMMENT_ID_LEN;
             if data.len() < expected {
-                return Err(SauceError::InvalidCommentBlock);
-            }
-            let mut cdata = &data[data.len() - expected..];
-            if &cdata[..COMMENT_ID_LEN] != COMMENT_ID {
-                // Non-fatal per spec: ignore comments
-                log::warn!("SAUCE comment block missing COMNT ID - ignoring comments");
+                if !options.recover_comments {
+                    return Err(SauceError::InvalidCommentBlock);
+                }
+                emit(
+                    diagnostics,
+                    DiagnosticKind::TruncatedCommentBlock,
+                    0,
+                    "SAUCE comment block truncated - ignoring comments",
+                );
             } else {
-                cdata = &cdata[COMMENT_ID_LEN..];
-                for _ in 0..header.comments {
-                    comments.push(trim_spaces(&cdata[..COMMENT_LEN]));
-                    cdata = &cdata[COMMENT_LEN..];
+                let block_start = data.len() - expected;
+                let mut cdata = &data[block_start..];
+                let has_id = cdata[..COMMENT_ID_LEN] == COMMENT_ID;
+                if !has_id && options.require_comment_id {
+                    return Err(SauceError::InvalidCommentBlock);
+                }
+                if has_id || options.recover_comments {
+                    if !has_id {
+                        emit(
+                            diagnostics,
+                            DiagnosticKind::MissingCommentId,
+                            block_start,
+                            "SAUCE comment block missing COMNT ID - reading comments anyway",
+                        );
+                    }
+                    cdata = &cdata[COMMENT_ID_LEN..];
+                    for _ in 0..header.comments {
+                        comments.push(trim_spaces(&cdata[..COMMENT_LEN]));
+                        cdata = &cdata[COMMENT_LEN..];
+                    }
+                } else {
+                    // Non-fatal per spec: ignore comments
+                    emit(
+                        diagnostics,
+                        DiagnosticKind::MissingCommentId,
+                        block_start,
+                        "SAUCE comment block missing COMNT ID - ignoring comments",
+                    );
                 }
             }
         }
 
         // Check EOF marker at the correct position
         // EOF should be right before the SAUCE data (including comment block if present)
-        let sauce_size = if header.comments > 0 {
-            HDR_LEN + header.comments as usize * COMMENT_LEN + COMMENT_ID_LEN
-        } else {
-            HDR_LEN
-        };
+        let sauce_size = header.total_length();
+        let has_eof = data.len() > sauce_size && data[data.len() - sauce_size - 1] == 0x1A;
+        if !has_eof {
+            if options.require_eof_marker {
+                return Err(SauceError::MissingEofMarker);
+            }
+            // Non fatal warning
+            if data.len() > sauce_size {
+                emit(
+                    diagnostics,
+                    DiagnosticKind::MissingEofMarker,
+                    data.len() - sauce_size - 1,
+                    "Missing EOF marker before SAUCE record",
+                );
+            }
+        }
 
-        // Non fatal warning
-        if data.len() > sauce_size {
-            let eof_pos = data.len() - sauce_size - 1;
-            if data[eof_pos] != 0x1A {
-                log::warn!("Missing EOF marker before SAUCE record");
+        let record = SauceRecord::new(header, comments);
+        if options.min_confidence > 0 {
+            let confidence = confidence::evaluate_at(data, data.len(), offset, &record);
+            if confidence.score < options.min_confidence {
+                emit(
+                    diagnostics,
+                    DiagnosticKind::LowConfidence,
+                    data.len() - HDR_LEN,
+                    format!(
+                        "SAUCE record ignored - confidence {} below {}",
+                        confidence.score, options.min_confidence
+                    ),
+                );
+                return Ok(None);
             }
         }
+        Ok(Some(record))
+    }
 
-        Ok(Some(SauceRecord {
-            header,
-            comments,
-            cached_caps: OnceCell::new(),
-        }))
+    /// Score how likely this record is genuine, given the `data` it was parsed from.
+    ///
+    /// `data` must end with this record, as passed to [`from_bytes`](Self::from_bytes).
+    /// See [`Confidence`] for the individual checks.
+    pub fn confidence(&self, data: &[u8]) -> Confidence {
+        confidence::evaluate(data, data.len(), self)
+    }
+
+    /// Parse every stacked SAUCE record at the end of `data`, outermost (last) first.
+    ///
+    /// Some tools append a new record instead of replacing the existing one, leaving a
+    /// stack of records behind. Records are walked backwards using the same contiguity
+    /// rule as [`StripMode::All`](crate::StripMode::All): at most one EOF marker may
+    /// separate two records.
+    ///
+    /// Each [`LocatedRecord`] carries the byte range of the record (comment block and
+    /// header, without the EOF marker) within `data`.
+    ///
+    /// # Errors
+    /// Only a malformed outermost record yields an error. A malformed inner record ends
+    /// the walk, the records found so far are returned.
+    ///
+    /// # Example
+    /// ```
+    /// use icy_sauce::{SauceRecord, SauceRecordBuilder};
+    /// use bstr::BString;
+    ///
+    /// let mut data = b"Content".to_vec();
+    /// SauceRecordBuilder::default().title(BString::from("First"))?.build().write(&mut data)?;
+    /// SauceRecordBuilder::default().title(BString::from("Second"))?.build().write(&mut data)?;
+    ///
+    /// let stack = SauceRecord::stack_from_bytes(&data)?;
+    /// assert_eq!(stack.len(), 2);
+    /// assert_eq!(stack[0].record.title(), &BString::from("Second"));
+    /// assert_eq!(stack[1].range, 8..136);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn stack_from_bytes(data: &[u8]) -> crate::Result<Vec<LocatedRecord>> {
+        let mut stack = Vec::new();
+        let mut cursor = data.len();
+        loop {
+            let record = match Self::from_bytes(&data[..cursor]) {
+                Ok(Some(record)) => record,
+                Ok(None) => break,
+                Err(err) if stack.is_empty() => return Err(err),
+                Err(_) => break,
+            };
+            let start = cursor.saturating_sub(record.header.total_length());
+            let eof_marker = start > 0 && data[start - 1] == 0x1A;
+            let confidence = confidence::evaluate(data, cursor, &record);
+            stack.push(LocatedRecord {
+                record,
+                range: start..cursor,
+                eof_marker,
+                trailing: 0,
+                confidence,
+            });
+            cursor = if eof_marker { start - 1 } else { start };
+        }
+        Ok(stack)
+    }
+
+    /// Search the tail of `data` for a SAUCE record that is followed by other bytes.
+    ///
+    /// Files that went through BBS transfers often carry junk after the record: XMODEM
+    /// 0x1A padding, NUL sector padding or appended download banners.
+    /// [`from_bytes`](Self::from_bytes) only looks at the last 128 bytes and reports no
+    /// SAUCE for such files. This function scans the last
+    /// [`limits::DEFAULT_SCAN_WINDOW`](crate::limits::DEFAULT_SCAN_WINDOW) bytes for the
+    /// `SAUCE00` signature instead, nearest to the end first, and returns the first
+    /// candidate that parses and passes basic sanity checks (known data type, complete
+    /// comment block, a [`Confidence`] score of at least
+    /// [`limits::DEFAULT_SCAN_CONFIDENCE`](crate::limits::DEFAULT_SCAN_CONFIDENCE)).
+    ///
+    /// A record located exactly at the end is found as well, with `trailing == 0`.
+    ///
+    /// # Example
+    /// ```
+    /// use icy_sauce::{SauceRecord, SauceRecordBuilder};
+    ///
+    /// let mut data = b"Content".to_vec();
+    /// SauceRecordBuilder::default().build().write(&mut data)?;
+    /// data.extend([0x1A; 40]); // XMODEM padding
+    ///
+    /// assert!(SauceRecord::from_bytes(&data)?.is_none());
+    /// let found = SauceRecord::find_in(&data).unwrap();
+    /// assert_eq!(found.trailing, 40);
+    /// assert_eq!(found.range, 8..136);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn find_in(data: &[u8]) -> Option<LocatedRecord> {
+        Self::find_in_window(data, crate::limits::DEFAULT_SCAN_WINDOW)
+    }
+
+    /// Like [`find_in`](Self::find_in), but only the last `window` bytes of `data` are
+    /// searched. The whole 128 byte header must lie inside the window.
+    pub fn find_in_window(data: &[u8], window: usize) -> Option<LocatedRecord> {
+        if data.len() < HDR_LEN {
+            return None;
+        }
+        let lowest = data.len().saturating_sub(window);
+        let mut pos = data.len() - HDR_LEN;
+        loop {
+            if pos < lowest {
+                return None;
+            }
+            if let Some(found) = Self::candidate_at(data, pos) {
+                return Some(found);
+            }
+            if pos == 0 {
+                return None;
+            }
+            pos -= 1;
+        }
+    }
+
+    /// Check whether a plausible SAUCE header starts at `pos`.
+    pub(crate) fn candidate_at(data: &[u8], pos: usize) -> Option<LocatedRecord> {
+        if &data[pos..pos + SAUCE_SIGNATURE.len()] != SAUCE_SIGNATURE {
+            return None;
+        }
+        let end = pos + HDR_LEN;
+        let header = SauceHeader::from_bytes(&data[..end]).ok().flatten()?;
+        if matches!(header.data_type, SauceDataType::Undefined(_)) {
+            return None;
+        }
+        let start = end.checked_sub(header.total_length())?;
+        if header.comments > 0 && data[start..start + COMMENT_ID_LEN] != COMMENT_ID {
+            return None;
+        }
+        let record = Self::from_bytes(&data[..end]).ok().flatten()?;
+        let confidence = confidence::evaluate(data, end, &record);
+        if confidence.score < crate::limits::DEFAULT_SCAN_CONFIDENCE {
+            return None;
+        }
+        Some(LocatedRecord {
+            record,
+            range: start..end,
+            eof_marker: start > 0 && data[start - 1] == 0x1A,
+            trailing: data.len() - end,
+            confidence,
+        })
     }
 
     /// Efficiently parse a SAUCE record from a file path.
     ///
-    /// Instead of reading the entire file, only the trailing window large enough to hold
-    /// the maximum possible SAUCE payload (header + comment block + COMNT marker + EOF) is read.
-    /// This keeps memory usage low for large artwork files.
+    /// Instead of reading the entire file, the tail is read in two steps: first the
+    /// 128-byte header and the EOF byte before it, then — only if the header announces
+    /// comments — the comment block. Files without a record or without comments cost a
+    /// single 129 byte read, which matters when crawling network-mounted archives.
+    /// With [`ParseOptions::scan_for_comments`] the second step reads the largest possible
+    /// comment block, as the announced count may be wrong.
     ///
     /// # Arguments
     /// * `path` - Path to the file on disk.
@@ -167,22 +526,55 @@ impl SauceRecord {
     /// # Errors
     /// I/O failures are wrapped in [`SauceError::IoError`]. Structural SAUCE issues yield
     /// specific `SauceError` variants.
+    #[cfg(feature = "std")]
     #[must_use]
+    #[allow(clippy::double_must_use)]
     pub fn from_path(path: &std::path::Path) -> crate::Result<Option<Self>> {
-        const MAX_SAUCE_WINDOW: u64 = 128 + 5 + 255 * 64 + 1;
+        Self::from_path_with_options(path, &ParseOptions::default())
+    }
+
+    /// Parse a SAUCE record from a file path with explicit parser tolerances.
+    ///
+    /// Reads the tail like [`from_path`](Self::from_path) and parses it with
+    /// [`from_bytes_with_options`](Self::from_bytes_with_options). For
+    /// [`ParseOptions::min_confidence`], `FileSize` is checked against the real file length.
+    #[cfg(feature = "std")]
+    pub fn from_path_with_options(
+        path: &std::path::Path,
+        options: &ParseOptions,
+    ) -> crate::Result<Option<Self>> {
         let mut f = File::open(path).map_err(|e| SauceError::io_error(path, e))?;
-        let file_len = f
-            .metadata()
-            .map_err(|e| SauceError::io_error(path, e))?
-            .len();
-        let read_len = MAX_SAUCE_WINDOW.min(file_len);
-        f.seek(SeekFrom::End(-(read_len as i64)))
-            .map_err(|e| SauceError::io_error(path, e))?;
-        let mut buf = vec![0u8; read_len as usize];
-        f.read_exact(&mut buf)
-            .map_err(|e| SauceError::io_error(path, e))?;
-        // Reuse existing logic
-        Self::from_bytes(&buf)
+        let (offset, buf) =
+            read_record_window(&mut f, options).map_err(|e| SauceError::io_error(path, e))?;
+        Self::from_window(&buf, offset, options)
+    }
+
+    /// Parse a SAUCE record from the end of any seekable stream.
+    ///
+    /// Reads the tail like [`from_path`](Self::from_path), so containers
+    /// and virtual filesystems don't have to load the whole entry. The stream is left
+    /// positioned at its end.
+    ///
+    /// # Errors
+    /// I/O failures are wrapped in [`SauceError::IoError`]. Structural SAUCE issues yield
+    /// specific `SauceError` variants.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: Read + Seek>(reader: &mut R) -> crate::Result<Option<Self>> {
+        Self::from_reader_with_options(reader, &ParseOptions::default())
+    }
+
+    /// Parse a SAUCE record from a seekable stream with explicit parser tolerances.
+    ///
+    /// See [`from_reader`](Self::from_reader) and
+    /// [`from_bytes_with_options`](Self::from_bytes_with_options).
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_options<R: Read + Seek>(
+        reader: &mut R,
+        options: &ParseOptions,
+    ) -> crate::Result<Option<Self>> {
+        let (offset, buf) =
+            read_record_window(reader, options).map_err(|e| SauceError::io_error("<reader>", e))?;
+        Self::from_window(&buf, offset, options)
     }
 
     /// Serialize this SAUCE record (including EOF marker) to a fresh `Vec<u8>`.
@@ -208,27 +600,52 @@ impl SauceRecord {
     }
 
     /// Write SAUCE with EOF marker (standard format).
-    pub fn write<W: Write>(&self, writer: &mut W) -> crate::Result<()> {
+    pub fn write<W: ByteSink>(&self, writer: &mut W) -> crate::Result<()> {
         self.write_internal(writer, true)
     }
 
     /// Write SAUCE without EOF marker (for special cases).
-    pub fn write_without_eof<W: Write>(&self, writer: &mut W) -> crate::Result<()> {
+    pub fn write_without_eof<W: ByteSink>(&self, writer: &mut W) -> crate::Result<()> {
         self.write_internal(writer, false)
     }
 
+    /// Write SAUCE with EOF marker, keeping the bytes of every unmodified field of `original`.
+    ///
+    /// `original` is typically captured from the file this record was read from. See
+    /// [`RawSauceHeader::apply`] for how modified fields are detected.
+    ///
+    /// # Example
+    /// ```
+    /// use icy_sauce::{RawSauceHeader, SauceRecord};
+    ///
+    /// # let data = icy_sauce::SauceRecordBuilder::default().build().to_bytes();
+    /// let raw = RawSauceHeader::from_bytes(&data).unwrap();
+    /// let record = raw.to_record();
+    /// let mut out = Vec::new();
+    /// record.write_preserving(&raw, &mut out)?;
+    /// assert_eq!(out, data);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_preserving<W: ByteSink>(
+        &self,
+        original: &RawSauceHeader,
+        writer: &mut W,
+    ) -> crate::Result<()> {
+        let mut raw = original.clone();
+        raw.apply(self);
+        raw.write(writer)
+    }
+
     /// Internal unified writer for both public write variants.
     ///
     /// When `eof` is true an EOF marker (0x1A) is prepended. Comments (if any) are serialized
     /// with a `COMNT` marker followed by each space‑padded 64 byte line. Finally the header
     /// (128 bytes) is written. In case of any I/O error an appropriate [`SauceError::IoError`]
     /// is returned.
-    fn write_internal<W: Write>(&self, writer: &mut W, eof: bool) -> crate::Result<()> {
+    fn write_internal<W: ByteSink>(&self, writer: &mut W, eof: bool) -> crate::Result<()> {
         // EOF Char.
         if eof {
-            if let Err(err) = writer.write_all(&[0x1A]) {
-                return Err(SauceError::io_error("<writer>", err));
-            }
+            writer.write_bytes(&[0x1A])?;
         }
 
         if !self.comments.is_empty() {
@@ -239,9 +656,7 @@ impl SauceRecord {
                 comment_info.extend(sauce_pad(comment, COMMENT_LEN));
             }
             assert_eq!(comment_info.len(), length);
-            if let Err(err) = writer.write_all(&comment_info) {
-                return Err(SauceError::io_error("<writer>", err));
-            }
+            writer.write_bytes(&comment_info)?;
         }
         self.header.write(writer)?;
         Ok(())
@@ -442,34 +857,7 @@ impl SauceRecord {
     /// }
     /// ```
     pub fn capabilities(&self) -> Option<Capabilities> {
-        self.cached_caps
-            .get_or_init(|| match self.header.data_type {
-                SauceDataType::Character => CharacterCapabilities::try_from(&self.header)
-                    .ok()
-                    .map(Capabilities::Character),
-                SauceDataType::BinaryText | SauceDataType::XBin => {
-                    BinaryCapabilities::try_from(&self.header)
-                        .ok()
-                        .map(Capabilities::Binary)
-                }
-                SauceDataType::Bitmap => BitmapCapabilities::try_from(&self.header)
-                    .ok()
-                    .map(Capabilities::Bitmap),
-                SauceDataType::Vector => VectorCapabilities::try_from(&self.header)
-                    .ok()
-                    .map(Capabilities::Vector),
-                SauceDataType::Audio => AudioCapabilities::try_from(&self.header)
-                    .ok()
-                    .map(Capabilities::Audio),
-                SauceDataType::Archive => ArchiveCapabilities::try_from(&self.header)
-                    .ok()
-                    .map(Capabilities::Archive),
-                SauceDataType::Executable => ExecutableCapabilities::try_from(&self.header)
-                    .ok()
-                    .map(Capabilities::Executable),
-                _ => None,
-            })
-            .clone()
+        self.caps.clone()
     }
 
     /// Extract basic metadata information.
@@ -501,6 +889,59 @@ impl SauceRecord {
         }
     }
 
+    /// Check this record against the SAUCE v00 specification.
+    ///
+    /// Runs all header checks of [`SauceHeader::validate`] and additionally verifies
+    /// the comment lines (count, length and control characters).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icy_sauce::{SauceRecordBuilder, SauceDate};
+    /// use bstr::BString;
+    ///
+    /// let sauce = SauceRecordBuilder::default()
+    ///     .date(SauceDate::new(2025, 11, 8))
+    ///     .add_comment(BString::from("Fine comment")).unwrap()
+    ///     .build();
+    /// assert!(sauce.validate().is_empty());
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        crate::validation::validate_record(self)
+    }
+
+    /// Like [`validate`](Self::validate) but also checks `FileSize` against the actual
+    /// length of the file content (without EOF marker and SAUCE record).
+    ///
+    /// A `FileSize` of 0 means "unknown" and is never reported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icy_sauce::{SauceRecord, SauceRecordBuilder, SauceDate, IssueKind, strip_sauce, StripMode};
+    ///
+    /// let mut data = b"Hello".to_vec();
+    /// SauceRecordBuilder::default()
+    ///     .date(SauceDate::new(2025, 11, 8))
+    ///     .file_size(10)
+    ///     .build()
+    ///     .write(&mut data)?;
+    ///
+    /// let sauce = SauceRecord::from_bytes(&data)?.unwrap();
+    /// let content = strip_sauce(&data, StripMode::LastStripFinalEof);
+    /// let report = sauce.validate_with_content_len(content.len() as u64);
+    /// assert_eq!(
+    ///     report.issues()[0].kind,
+    ///     IssueKind::FileSizeMismatch { declared: 10, actual: 5 }
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_with_content_len(&self, content_len: u64) -> ValidationReport {
+        let mut report = self.validate();
+        crate::validation::check_file_size(&mut report, &self.header, content_len);
+        report
+    }
+
     /// Convert this SAUCE record to a builder for modification.
     ///
     /// This allows you to create a modified copy of an existing SAUCE record.
@@ -532,3 +973,60 @@ impl SauceRecord {
         }
     }
 }
+
+/// Read the tail of `reader` needed to parse its record: the header and EOF byte first,
+/// then as much as [`record_window_len`] asks for. Returns the window's offset in the
+/// stream along with the bytes.
+#[cfg(feature = "std")]
+fn read_record_window<R: Read + Seek>(
+    reader: &mut R,
+    options: &ParseOptions,
+) -> std::io::Result<(u64, Vec<u8>)> {
+    let stream_len = reader.seek(SeekFrom::End(0))?;
+    let mut buf = read_last(reader, stream_len, HDR_LEN + 1, Vec::new())?;
+    let needed = record_window_len(&buf, options);
+    if needed > buf.len() {
+        buf = read_last(reader, stream_len, needed, buf)?;
+        reader.seek(SeekFrom::End(0))?;
+    }
+    Ok((stream_len - buf.len() as u64, buf))
+}
+
+/// Number of trailing bytes needed to parse the record whose header ends `tail`.
+///
+/// `tail` holds at least the last 129 bytes of the stream (or all of it). Returns
+/// `tail.len()` if no more bytes are needed.
+#[cfg(feature = "std")]
+pub(crate) fn record_window_len(tail: &[u8], options: &ParseOptions) -> usize {
+    let Some(header_start) = tail.len().checked_sub(HDR_LEN) else {
+        return tail.len();
+    };
+    if !tail[header_start..].starts_with(SAUCE_ID) {
+        return tail.len();
+    }
+    if options.scan_for_comments {
+        return crate::limits::MAX_RECORD_LEN;
+    }
+    match tail[header_start + field::COMMENTS] as usize {
+        0 => tail.len(),
+        count => 1 + COMMENT_ID_LEN + count * COMMENT_LEN + HDR_LEN,
+    }
+}
+
+/// Read the last `len` bytes of a stream of `stream_len` bytes (or all of it, if shorter),
+/// given that its last `known.len()` bytes have already been read into `known`.
+#[cfg(feature = "std")]
+pub(crate) fn read_last<R: Read + Seek>(
+    reader: &mut R,
+    stream_len: u64,
+    len: usize,
+    known: Vec<u8>,
+) -> std::io::Result<Vec<u8>> {
+    let len = (len as u64).min(stream_len);
+    let missing = (len - known.len() as u64) as usize;
+    reader.seek(SeekFrom::Start(stream_len - len))?;
+    let mut buf = vec![0u8; missing];
+    reader.read_exact(&mut buf)?;
+    buf.extend(known);
+    Ok(buf)
+}
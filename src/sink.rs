@@ -0,0 +1,61 @@
+//! Output abstraction for serializing records with and without `std`.
+//!
+//! The `write` methods of [`SauceRecord`](crate::SauceRecord),
+//! [`SauceHeader`](crate::header::SauceHeader) and friends accept any [`ByteSink`].
+//! `Vec<u8>` implements it with or without `std`, and embedded code can implement it
+//! for its own buffers or serial ports. With the default `std` feature, any
+//! [`std::io::Write`] such as a file or socket becomes a sink by wrapping it in
+//! [`IoSink`]. The implementations are the same for every feature set, so enabling
+//! `std` never changes which types are sinks.
+
+use alloc::vec::Vec;
+
+/// Destination for serialized SAUCE data.
+pub trait ByteSink {
+    /// Write all of `buf`.
+    ///
+    /// # Errors
+    /// Implementations outside of `std` report failures as
+    /// [`SauceError::WriteFailed`](crate::SauceError::WriteFailed).
+    fn write_bytes(&mut self, buf: &[u8]) -> crate::Result<()>;
+}
+
+impl ByteSink for Vec<u8> {
+    fn write_bytes(&mut self, buf: &[u8]) -> crate::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+}
+
+impl<S: ByteSink + ?Sized> ByteSink for &mut S {
+    fn write_bytes(&mut self, buf: &[u8]) -> crate::Result<()> {
+        (**self).write_bytes(buf)
+    }
+}
+
+/// Adapts a [`std::io::Write`] to [`ByteSink`].
+///
+/// Write failures are wrapped in [`SauceError::IoError`](crate::SauceError::IoError).
+///
+/// # Example
+/// ```
+/// use icy_sauce::{IoSink, SauceRecordBuilder};
+/// use std::io::Cursor;
+///
+/// let mut out = IoSink(Cursor::new(Vec::new()));
+/// SauceRecordBuilder::default().build().write(&mut out)?;
+/// assert_eq!(out.0.into_inner().len(), 129);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IoSink<W>(pub W);
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> ByteSink for IoSink<W> {
+    fn write_bytes(&mut self, buf: &[u8]) -> crate::Result<()> {
+        self.0
+            .write_all(buf)
+            .map_err(|e| crate::SauceError::io_error("<writer>", e))
+    }
+}
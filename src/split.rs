@@ -0,0 +1,125 @@
+//! Splitting concatenated files.
+//!
+//! `copy /b a.ans+b.ans all.ans` produces a file with a SAUCE record in the middle,
+//! followed by the next artwork. [`split_concatenated`] walks such data, using each
+//! embedded record (and its `file_size` and EOF marker) to find the boundaries between
+//! the original files.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{SauceRecordBuilder, split_concatenated};
+//! use bstr::BString;
+//!
+//! let mut data = Vec::new();
+//! for (content, title) in [(&b"First art"[..], "A"), (&b"Second"[..], "B")] {
+//!     data.extend(content);
+//!     SauceRecordBuilder::default()
+//!         .title(BString::from(title))?
+//!         .file_size(content.len() as u32)
+//!         .build()
+//!         .write(&mut data)?;
+//! }
+//!
+//! let parts = split_concatenated(&data);
+//! assert_eq!(parts.len(), 2);
+//! assert_eq!(parts[0].content, b"First art");
+//! assert_eq!(parts[1].content, b"Second");
+//! assert_eq!(parts[1].record.as_ref().unwrap().title(), &BString::from("B"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{SauceRecord, header::HDR_LEN};
+
+/// One of the original files found by [`split_concatenated`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPart<'a> {
+    /// The file content, without EOF marker and SAUCE record.
+    pub content: &'a [u8],
+    /// The SAUCE record that ended this part, if any.
+    pub record: Option<SauceRecord>,
+    /// Byte range of the whole part (content, EOF marker and record) in the input.
+    pub range: Range<usize>,
+}
+
+/// Split data made of several concatenated files at their embedded SAUCE records.
+///
+/// Every plausible record (see [`SauceRecord::find_in`]) ends a part. If a record's
+/// `file_size` is non-zero and shorter than the data before it, the file size is used to
+/// find where its content starts; any bytes in front of that become a separate part
+/// without record. Bytes after the last record form a final part without record.
+///
+/// Records stacked directly after another record (with at most an EOF marker between)
+/// belong to the same part, which then carries the last of them, like
+/// [`SauceRecord::from_bytes`] would report for that part.
+///
+/// Returns an empty list for empty input and a single part for data without
+/// embedded records.
+pub fn split_concatenated(data: &[u8]) -> Vec<SplitPart<'_>> {
+    let mut parts: Vec<SplitPart<'_>> = Vec::new();
+    let mut part_start = 0;
+    let mut pos = 0;
+
+    while pos + HDR_LEN <= data.len() {
+        let Some(found) = SauceRecord::candidate_at(data, pos) else {
+            pos += 1;
+            continue;
+        };
+        if found.range.start < part_start {
+            // The comment block would reach into the previous part
+            pos += 1;
+            continue;
+        }
+
+        let content_end = if found.eof_marker && found.range.start > part_start {
+            found.range.start - 1
+        } else {
+            found.range.start
+        };
+        let file_size = found.record.file_size() as usize;
+        let content_start = if file_size > 0 && file_size < content_end - part_start {
+            content_end - file_size
+        } else {
+            part_start
+        };
+
+        if content_end == part_start
+            && let Some(last) = parts.last_mut()
+            && last.record.is_some()
+            && last.range.end == part_start
+        {
+            last.record = Some(found.record);
+            last.range.end = found.range.end;
+            part_start = found.range.end;
+            pos = part_start;
+            continue;
+        }
+
+        if content_start > part_start {
+            parts.push(SplitPart {
+                content: &data[part_start..content_start],
+                record: None,
+                range: part_start..content_start,
+            });
+        }
+        parts.push(SplitPart {
+            content: &data[content_start..content_end],
+            record: Some(found.record),
+            range: content_start..found.range.end,
+        });
+        part_start = found.range.end;
+        pos = part_start;
+    }
+
+    if part_start < data.len() {
+        parts.push(SplitPart {
+            content: &data[part_start..],
+            record: None,
+            range: part_start..data.len(),
+        });
+    }
+    parts
+}
@@ -0,0 +1,386 @@
+//! SAUCE detection on non-seekable streams.
+//!
+//! Standard input, pipes and sockets can't seek back to read the record at the end.
+//! [`StreamDetector`] consumes the stream once, keeping only the last
+//! [`MAX_RECORD_LEN`] bytes in a ring buffer. Everything older is known to be content
+//! and is forwarded to a sink right away; at the end the buffer is parsed and the
+//! remaining content is forwarded too. The whole file is never held in memory.
+//!
+//! [`SauceStrippingReader`] is the pull based counterpart: a [`Read`] adapter that
+//! yields only the content, e.g. for piping an .ANS file to a terminal.
+//! [`SauceAppendingWriter`] goes the other way and appends a record after streamed
+//! content.
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{SauceRecordBuilder, detect_stream};
+//! use bstr::BString;
+//!
+//! let mut upload = b"Uploaded art".to_vec();
+//! SauceRecordBuilder::default()
+//!     .title(BString::from("Title"))?
+//!     .build()
+//!     .write(&mut upload)?;
+//!
+//! // In a real pipeline: detect_stream(std::io::stdin().lock(), file)
+//! let result = detect_stream(&upload[..], Vec::new())?;
+//! assert_eq!(result.record.unwrap().title(), &BString::from("Title"));
+//! assert_eq!(result.content_len, 12);
+//! assert_eq!(result.sink, b"Uploaded art");
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+};
+
+use crate::{
+    IoSink, ParseOptions, SauceError, SauceRecord, SauceRecordBuilder, StripMode,
+    limits::MAX_RECORD_LEN, strip_sauce_ex,
+};
+
+/// Outcome of [`StreamDetector::finish`] and [`detect_stream`].
+#[derive(Debug)]
+pub struct StreamResult<W> {
+    /// The SAUCE record at the end of the stream, if any.
+    pub record: Option<SauceRecord>,
+    /// Number of content bytes forwarded to the sink: the stream without the record and
+    /// the EOF marker directly before it.
+    pub content_len: u64,
+    /// Total number of bytes consumed from the stream.
+    pub total_len: u64,
+    /// The sink, after all content has been written and flushed.
+    pub sink: W,
+}
+
+/// Forwards stream content to a sink while watching for a trailing SAUCE record.
+///
+/// Feed data with [`feed`](Self::feed) or through the [`Write`] implementation, then
+/// call [`finish`](Self::finish) at the end of the stream. At most [`MAX_RECORD_LEN`]
+/// bytes are buffered; the sink lags behind the input by that much until `finish`.
+#[derive(Debug)]
+pub struct StreamDetector<W: Write> {
+    sink: W,
+    buffer: VecDeque<u8>,
+    forwarded: u64,
+    options: ParseOptions,
+}
+
+impl<W: Write> StreamDetector<W> {
+    /// Create a detector forwarding content to `sink`, parsing with default options.
+    pub fn new(sink: W) -> Self {
+        Self::with_options(sink, ParseOptions::default())
+    }
+
+    /// Create a detector parsing the final record with `options`.
+    pub fn with_options(sink: W, options: ParseOptions) -> Self {
+        Self {
+            sink,
+            buffer: VecDeque::with_capacity(MAX_RECORD_LEN),
+            forwarded: 0,
+            options,
+        }
+    }
+
+    /// Consume the next chunk of the stream.
+    ///
+    /// Bytes that can no longer be part of a trailing record are written to the sink.
+    ///
+    /// # Errors
+    /// Errors from writing to the sink.
+    pub fn feed(&mut self, data: &[u8]) -> io::Result<()> {
+        let overflow = (self.buffer.len() + data.len()).saturating_sub(MAX_RECORD_LEN);
+        let from_buffer = overflow.min(self.buffer.len());
+        if from_buffer > 0 {
+            let (front, back) = self.buffer.as_slices();
+            let from_front = from_buffer.min(front.len());
+            self.sink.write_all(&front[..from_front])?;
+            self.sink.write_all(&back[..from_buffer - from_front])?;
+            self.buffer.drain(..from_buffer);
+        }
+        let from_data = overflow - from_buffer;
+        self.sink.write_all(&data[..from_data])?;
+        self.buffer.extend(&data[from_data..]);
+        self.forwarded += overflow as u64;
+        Ok(())
+    }
+
+    /// End of stream: parse the buffered tail and forward the remaining content.
+    ///
+    /// Only the last record is removed; older stacked records are forwarded as content.
+    ///
+    /// # Errors
+    /// [`SauceError::IoError`] if writing to the sink fails; structural
+    /// SAUCE issues yield the same errors as [`SauceRecord::from_bytes_with_options`]. The
+    /// buffered tail is not forwarded in that case.
+    pub fn finish(mut self) -> crate::Result<StreamResult<W>> {
+        let total_len = self.forwarded + self.buffer.len() as u64;
+        let tail = self.buffer.make_contiguous();
+        let record = SauceRecord::from_bytes_with_options(tail, &self.options)?;
+        let mut content_end = tail.len();
+        if let Some(record) = &record {
+            // A lenient parse keeps a record whose comment block is cut off by the
+            // start of the stream; only its header is present then.
+            let mut record_len = record.header().total_length();
+            if record_len > content_end {
+                record_len = record.record_len();
+            }
+            content_end -= record_len;
+            if content_end > 0 && tail[content_end - 1] == 0x1A {
+                content_end -= 1;
+            }
+        }
+        self.sink
+            .write_all(&tail[..content_end])
+            .and_then(|()| self.sink.flush())
+            .map_err(|e| SauceError::io_error("<writer>", e))?;
+        Ok(StreamResult {
+            record,
+            content_len: self.forwarded + content_end as u64,
+            total_len,
+            sink: self.sink,
+        })
+    }
+}
+
+impl<W: Write> Write for StreamDetector<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.feed(buf)?;
+        Ok(buf.len())
+    }
+
+    /// Flushes the sink; buffered bytes that may belong to a record are kept.
+    fn flush(&mut self) -> io::Result<()> {
+        self.sink.flush()
+    }
+}
+
+/// Copy `reader` to `sink`, removing a trailing SAUCE record and returning it.
+///
+/// See [`StreamDetector`].
+///
+/// # Errors
+/// I/O failures are wrapped in [`SauceError::IoError`]; see also
+/// [`StreamDetector::finish`].
+pub fn detect_stream<R: Read, W: Write>(mut reader: R, sink: W) -> crate::Result<StreamResult<W>> {
+    let mut detector = StreamDetector::new(sink);
+    let mut buf = vec![0u8; READ_CHUNK];
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(SauceError::io_error("<reader>", e)),
+        };
+        detector
+            .feed(&buf[..n])
+            .map_err(|e| SauceError::io_error("<writer>", e))?;
+    }
+    detector.finish()
+}
+
+/// Number of bytes [`SauceStrippingReader`] asks the inner reader for at once.
+const READ_CHUNK: usize = 8192;
+
+/// A [`Read`] adapter that yields the content of a stream without its SAUCE record.
+///
+/// Records and EOF markers are removed with the same semantics as [`strip_sauce_ex`]
+/// in the given [`StripMode`]. The last [`MAX_RECORD_LEN`] bytes are held back until
+/// the inner reader is exhausted, everything before them is passed through as it
+/// arrives. With [`StripMode::All`] and [`StripMode::AllStripFinalEof`], stacked
+/// records are removed as long as they lie within that window.
+///
+/// ```
+/// use icy_sauce::{SauceRecordBuilder, SauceStrippingReader, StripMode};
+/// use bstr::BString;
+/// use std::io::Read;
+///
+/// let mut file = b"\x1b[1mHello".to_vec();
+/// SauceRecordBuilder::default().title(BString::from("Hi"))?.build().write(&mut file)?;
+///
+/// let mut reader = SauceStrippingReader::new(&file[..], StripMode::LastStripFinalEof);
+/// let mut shown = Vec::new();
+/// reader.read_to_end(&mut shown)?;
+/// assert_eq!(shown, b"\x1b[1mHello");
+/// assert_eq!(reader.record().unwrap().title(), &BString::from("Hi"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct SauceStrippingReader<R> {
+    inner: R,
+    mode: StripMode,
+    buffer: Vec<u8>,
+    /// Bytes `buffer[emitted..ready]` may be returned to the caller.
+    emitted: usize,
+    ready: usize,
+    finished: bool,
+    records_removed: usize,
+    record: Option<SauceRecord>,
+}
+
+impl<R: Read> SauceStrippingReader<R> {
+    /// Wrap `inner`, removing records according to `mode`.
+    pub fn new(inner: R, mode: StripMode) -> Self {
+        Self {
+            inner,
+            mode,
+            buffer: Vec::with_capacity(MAX_RECORD_LEN + READ_CHUNK),
+            emitted: 0,
+            ready: 0,
+            finished: false,
+            records_removed: 0,
+            record: None,
+        }
+    }
+
+    /// Returns `true` once the inner reader has been read to its end.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// The last SAUCE record of the stream.
+    ///
+    /// `None` until the stream [is finished](Self::is_finished), if the stream has no
+    /// record, or if the record could not be parsed (it is still stripped).
+    pub fn record(&self) -> Option<&SauceRecord> {
+        self.record.as_ref()
+    }
+
+    /// Number of records removed; 0 until the stream is finished.
+    pub fn records_removed(&self) -> usize {
+        self.records_removed
+    }
+
+    /// Unwrap the inner reader. Held back bytes are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Read the next chunk from the inner reader and update the ready range.
+    fn fill(&mut self) -> io::Result<()> {
+        self.buffer.drain(..self.emitted);
+        self.ready -= self.emitted;
+        self.emitted = 0;
+
+        let start = self.buffer.len();
+        self.buffer.resize(start + READ_CHUNK, 0);
+        let read = match self.inner.read(&mut self.buffer[start..]) {
+            Ok(read) => read,
+            Err(err) => {
+                self.buffer.truncate(start);
+                return Err(err);
+            }
+        };
+        self.buffer.truncate(start + read);
+        if read == 0 {
+            let result = strip_sauce_ex(&self.buffer, self.mode);
+            self.records_removed = result.records_removed;
+            self.ready = result.data.len();
+            if result.records_removed > 0 {
+                self.record = SauceRecord::from_bytes(&self.buffer).ok().flatten();
+            }
+            self.finished = true;
+        } else {
+            self.ready = self.buffer.len().saturating_sub(MAX_RECORD_LEN);
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for SauceStrippingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while self.emitted == self.ready {
+            if self.finished {
+                return Ok(0);
+            }
+            self.fill()?;
+        }
+        let len = buf.len().min(self.ready - self.emitted);
+        buf[..len].copy_from_slice(&self.buffer[self.emitted..self.emitted + len]);
+        self.emitted += len;
+        Ok(len)
+    }
+}
+
+/// A [`Write`] adapter that appends a SAUCE record after the content written through it.
+///
+/// Bytes are passed straight to the inner writer and counted. [`finish`](Self::finish)
+/// writes the EOF marker and the record built from the builder, with `file_size` set
+/// to the number of content bytes.
+///
+/// ```
+/// use icy_sauce::{SauceAppendingWriter, SauceRecord, SauceRecordBuilder};
+/// use bstr::BString;
+/// use std::io::Write;
+///
+/// let builder = SauceRecordBuilder::default().title(BString::from("Export"))?;
+/// let mut writer = SauceAppendingWriter::new(Vec::new(), builder);
+/// writer.write_all(b"Exported ")?;
+/// writer.write_all(b"content")?;
+/// let (data, record) = writer.finish()?;
+///
+/// assert_eq!(record.file_size(), 16);
+/// assert_eq!(SauceRecord::from_bytes(&data)?, Some(record));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct SauceAppendingWriter<W: Write> {
+    inner: W,
+    builder: SauceRecordBuilder,
+    written: u64,
+}
+
+impl<W: Write> SauceAppendingWriter<W> {
+    /// Wrap `inner`; the record appended by [`finish`](Self::finish) is built from `builder`.
+    pub fn new(inner: W, builder: SauceRecordBuilder) -> Self {
+        Self {
+            inner,
+            builder,
+            written: 0,
+        }
+    }
+
+    /// Number of content bytes written so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.written
+    }
+
+    /// Access the record builder, e.g. to fill in metadata known only after exporting.
+    pub fn builder_mut(&mut self) -> &mut SauceRecordBuilder {
+        &mut self.builder
+    }
+
+    /// Write the EOF marker and the record, flush, and return the inner writer and the
+    /// record that was written.
+    ///
+    /// # Errors
+    /// [`SauceError::FileTooLarge`] if more than `u32::MAX` bytes were written (nothing
+    /// is appended in that case); write failures are wrapped in [`SauceError::IoError`].
+    pub fn finish(mut self) -> crate::Result<(W, SauceRecord)> {
+        let file_size =
+            u32::try_from(self.written).map_err(|_| SauceError::FileTooLarge(self.written))?;
+        let record = self.builder.file_size(file_size).build();
+        record.write(&mut IoSink(&mut self.inner))?;
+        self.inner
+            .flush()
+            .map_err(|e| SauceError::io_error("<writer>", e))?;
+        Ok((self.inner, record))
+    }
+}
+
+impl<W: Write> Write for SauceAppendingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
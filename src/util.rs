@@ -1,6 +1,12 @@
+use alloc::{format, string::String, vec::Vec};
 use bstr::BString;
 
 pub(crate) fn trim_spaces(buf: &[u8]) -> bstr::BString {
+    bstr::BString::from(trim_spaces_slice(buf))
+}
+
+/// Borrowing variant of [`trim_spaces`]: cuts trailing spaces and zero bytes.
+pub(crate) fn trim_spaces_slice(buf: &[u8]) -> &[u8] {
     let mut end = buf.len();
     while end > 0 {
         let b = buf[end - 1];
@@ -9,7 +15,7 @@ pub(crate) fn trim_spaces(buf: &[u8]) -> bstr::BString {
         }
         end -= 1;
     }
-    bstr::BString::from(&buf[..end])
+    &buf[..end]
 }
 
 /// Pads trailing whitespaces or cut too long data.
@@ -28,9 +34,39 @@ pub(crate) fn zero_pad(str: &BString, len: usize) -> Vec<u8> {
 
 /// Trim only trailing zero bytes (binary zero padding) – for zero padded fields like TInfoS.
 pub(crate) fn zero_trim(data: &[u8]) -> BString {
+    BString::from(zero_trim_slice(data))
+}
+
+/// Borrowing variant of [`zero_trim`].
+pub(crate) fn zero_trim_slice(data: &[u8]) -> &[u8] {
     let mut end = data.len();
     while end > 0 && data[end - 1] == 0 {
         end -= 1;
     }
-    BString::new(data[..end].to_vec())
+    &data[..end]
+}
+
+/// Lowercase hex encoding used by the text formats of edit plans and batch journals.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    use core::fmt::Write;
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        let _ = write!(out, "{b:02x}");
+    }
+    out
+}
+
+/// Decode [`to_hex`] output. The error describes the problem.
+pub(crate) fn from_hex(text: &str) -> Result<Vec<u8>, String> {
+    let text = text.trim();
+    if !text.len().is_multiple_of(2) {
+        return Err("odd number of hex digits".into());
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| {
+            let digits = text.get(i..i + 2).ok_or("invalid hex")?;
+            u8::from_str_radix(digits, 16).map_err(|_| format!("invalid hex '{digits}'"))
+        })
+        .collect()
 }
@@ -0,0 +1,468 @@
+//! Structured validation of SAUCE headers and records.
+//!
+//! Parsing a SAUCE record is deliberately forgiving: most spec deviations are
+//! accepted so that old files can still be read. [`SauceHeader::validate`] and
+//! [`SauceRecord::validate`] report those deviations as a list of typed
+//! [`ValidationIssue`]s instead, each carrying a [`Severity`] and the name of
+//! the offending field.
+//!
+//! # Severity Levels
+//!
+//! - [`Severity::Error`]: the record is structurally broken (e.g. a BinaryText
+//!   width of 0, or a comment count that does not match the comment lines)
+//! - [`Severity::Warning`]: the record violates the spec but is still usable
+//!   (e.g. an invalid date, reserved flag bits, non-zero reserved fields)
+//! - [`Severity::Info`]: unusual but legal values (e.g. unknown file types)
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{SauceRecordBuilder, SauceDate, Severity};
+//! use bstr::BString;
+//!
+//! let record = SauceRecordBuilder::default()
+//!     .title(BString::from("Bad\x07Title"))?
+//!     .date(SauceDate::new(2025, 13, 1))
+//!     .build();
+//!
+//! let report = record.validate();
+//! assert!(!report.has_errors());
+//! assert_eq!(report.max_severity(), Some(Severity::Warning));
+//! assert!(report.issues().iter().any(|i| i.field == "title"));
+//! assert!(report.issues().iter().any(|i| i.field == "date"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`SauceHeader::validate`]: crate::header::SauceHeader::validate
+//! [`SauceRecord::validate`]: crate::SauceRecord::validate
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use crate::{
+    AudioFormat, CharacterFormat, SauceDataType, SauceRecord,
+    character::{ANSI_FLAG_NON_BLINK_MODE, ANSI_MASK_ASPECT_RATIO, ANSI_MASK_LETTER_SPACING},
+    header::SauceHeader,
+    limits,
+};
+
+/// How serious a [`ValidationIssue`] is.
+///
+/// Severities are ordered, so `Severity::Error > Severity::Warning > Severity::Info`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Unusual but legal value; worth surfacing, never worth rejecting.
+    Info,
+    /// Spec violation that readers are expected to tolerate.
+    Warning,
+    /// Structural problem; the record should not be trusted as-is.
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// The kind of problem a [`ValidationIssue`] describes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The date is not a valid CCYYMMDD value (unparsable dates read as `0000/00/00`).
+    InvalidDate,
+    /// `FileSize` is set but does not match the actual content length.
+    FileSizeMismatch { declared: u32, actual: u64 },
+    /// `DataType` is not one of the values defined by the spec.
+    UnknownDataType(u8),
+    /// `FileType` is not defined for the record's data type.
+    UnknownFileType(u8),
+    /// BinaryText width (FileType × 2) is outside the valid range 2-510.
+    InvalidBinaryTextWidth(u16),
+    /// The letter spacing bits of `TFlags` hold the reserved value `11`.
+    ReservedLetterSpacing,
+    /// The aspect ratio bits of `TFlags` hold the reserved value `11`.
+    ReservedAspectRatio,
+    /// `TFlags` has bits set that are undefined for the record's type.
+    ReservedFlagBits(u8),
+    /// A field the spec requires to be 0 (or empty) for this type is set.
+    ReservedFieldSet,
+    /// A field has a value other than the one fixed by the spec.
+    UnexpectedValue { expected: u16, actual: u16 },
+    /// A text field contains control characters (bytes < 0x20 or 0x7F).
+    ControlCharacters,
+    /// A text field is longer than the space reserved for it in the header.
+    FieldTooLong { len: usize, max: usize },
+    /// The header's comment count does not match the number of comment lines.
+    CommentCountMismatch { declared: u8, actual: usize },
+}
+
+impl Display for IssueKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IssueKind::InvalidDate => write!(f, "invalid or missing date"),
+            IssueKind::FileSizeMismatch { declared, actual } => write!(
+                f,
+                "file size {} does not match content length {}",
+                declared, actual
+            ),
+            IssueKind::UnknownDataType(dt) => write!(f, "unknown data type {}", dt),
+            IssueKind::UnknownFileType(ft) => write!(f, "unknown file type {}", ft),
+            IssueKind::InvalidBinaryTextWidth(w) => {
+                write!(f, "binary text width {} outside 2-510", w)
+            }
+            IssueKind::ReservedLetterSpacing => write!(f, "reserved letter spacing value"),
+            IssueKind::ReservedAspectRatio => write!(f, "reserved aspect ratio value"),
+            IssueKind::ReservedFlagBits(bits) => {
+                write!(f, "undefined flag bits set (0b{:08b})", bits)
+            }
+            IssueKind::ReservedFieldSet => write!(f, "must be 0 for this data type"),
+            IssueKind::UnexpectedValue { expected, actual } => {
+                write!(f, "expected {} but found {}", expected, actual)
+            }
+            IssueKind::ControlCharacters => write!(f, "contains control characters"),
+            IssueKind::FieldTooLong { len, max } => {
+                write!(
+                    f,
+                    "{} bytes long, only up to {} bytes are allowed",
+                    len, max
+                )
+            }
+            IssueKind::CommentCountMismatch { declared, actual } => write!(
+                f,
+                "header declares {} comment lines but record has {}",
+                declared, actual
+            ),
+        }
+    }
+}
+
+/// A single problem found by validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// How serious the issue is.
+    pub severity: Severity,
+    /// Name of the affected field (matches the [`SauceHeader`] field names,
+    /// e.g. `"title"`, `"t_info1"`, `"comments"`).
+    pub field: &'static str,
+    /// What is wrong with the field.
+    pub kind: IssueKind,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}: {}", self.severity, self.field, self.kind)
+    }
+}
+
+/// The outcome of validating a header or record.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// All issues, in the order they were found.
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Returns `true` if no issues were found at all.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns `true` if any issue has [`Severity::Error`].
+    pub fn has_errors(&self) -> bool {
+        self.max_severity() == Some(Severity::Error)
+    }
+
+    /// The highest severity among all issues, or `None` if there are none.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.issues.iter().map(|i| i.severity).max()
+    }
+
+    /// Iterate over issues with at least the given severity.
+    pub fn at_least(&self, severity: Severity) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(move |i| i.severity >= severity)
+    }
+
+    pub(crate) fn push(&mut self, severity: Severity, field: &'static str, kind: IssueKind) {
+        self.issues.push(ValidationIssue {
+            severity,
+            field,
+            kind,
+        });
+    }
+}
+
+impl IntoIterator for ValidationReport {
+    type Item = ValidationIssue;
+    type IntoIter = alloc::vec::IntoIter<ValidationIssue>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.into_iter()
+    }
+}
+
+fn has_control_chars(text: &[u8]) -> bool {
+    text.iter().any(|&b| b < 0x20 || b == 0x7F)
+}
+
+fn check_text(report: &mut ValidationReport, field: &'static str, text: &[u8], max: usize) {
+    if text.len() > max {
+        report.push(
+            Severity::Error,
+            field,
+            IssueKind::FieldTooLong {
+                len: text.len(),
+                max,
+            },
+        );
+    }
+    if has_control_chars(text) {
+        report.push(Severity::Warning, field, IssueKind::ControlCharacters);
+    }
+}
+
+fn require_zero(report: &mut ValidationReport, field: &'static str, value: u16) {
+    if value != 0 {
+        report.push(Severity::Warning, field, IssueKind::ReservedFieldSet);
+    }
+}
+
+fn require_value(report: &mut ValidationReport, field: &'static str, expected: u16, actual: u16) {
+    if expected != actual {
+        report.push(
+            Severity::Warning,
+            field,
+            IssueKind::UnexpectedValue { expected, actual },
+        );
+    }
+}
+
+/// Require TFlags and TInfoS to be unused.
+fn require_no_flags_or_font(report: &mut ValidationReport, header: &SauceHeader) {
+    require_zero(report, "t_flags", header.t_flags as u16);
+    if !header.t_info_s.is_empty() {
+        report.push(Severity::Warning, "t_info_s", IssueKind::ReservedFieldSet);
+    }
+}
+
+/// Check the ANSi flags byte used by ASCII/ANSI/ANSiMation and BinaryText.
+fn check_ansi_flags(report: &mut ValidationReport, header: &SauceHeader) {
+    let flags = header.t_flags;
+    if flags & ANSI_MASK_LETTER_SPACING == ANSI_MASK_LETTER_SPACING {
+        report.push(
+            Severity::Warning,
+            "t_flags",
+            IssueKind::ReservedLetterSpacing,
+        );
+    }
+    if flags & ANSI_MASK_ASPECT_RATIO == ANSI_MASK_ASPECT_RATIO {
+        report.push(Severity::Warning, "t_flags", IssueKind::ReservedAspectRatio);
+    }
+    let undefined =
+        flags & !(ANSI_FLAG_NON_BLINK_MODE | ANSI_MASK_LETTER_SPACING | ANSI_MASK_ASPECT_RATIO);
+    if undefined != 0 {
+        report.push(
+            Severity::Warning,
+            "t_flags",
+            IssueKind::ReservedFlagBits(undefined),
+        );
+    }
+}
+
+/// Check the type-dependent fields (FileType, TInfo1-4, TFlags, TInfoS) against
+/// the per-type layout in the SAUCE spec.
+fn check_type_fields(report: &mut ValidationReport, header: &SauceHeader) {
+    match header.data_type {
+        SauceDataType::None => {
+            require_zero(report, "file_type", header.file_type as u16);
+            require_zero(report, "t_info1", header.t_info1);
+            require_zero(report, "t_info2", header.t_info2);
+            require_zero(report, "t_info3", header.t_info3);
+            require_zero(report, "t_info4", header.t_info4);
+            require_no_flags_or_font(report, header);
+        }
+        SauceDataType::Character => match CharacterFormat::from_sauce(header.file_type) {
+            CharacterFormat::Ascii | CharacterFormat::Ansi | CharacterFormat::AnsiMation => {
+                require_zero(report, "t_info3", header.t_info3);
+                require_zero(report, "t_info4", header.t_info4);
+                check_ansi_flags(report, header);
+            }
+            CharacterFormat::RipScript => {
+                require_value(report, "t_info1", 640, header.t_info1);
+                require_value(report, "t_info2", 350, header.t_info2);
+                require_value(report, "t_info3", 16, header.t_info3);
+                require_zero(report, "t_info4", header.t_info4);
+                require_no_flags_or_font(report, header);
+            }
+            CharacterFormat::PCBoard | CharacterFormat::Avatar | CharacterFormat::TundraDraw => {
+                require_zero(report, "t_info3", header.t_info3);
+                require_zero(report, "t_info4", header.t_info4);
+                require_no_flags_or_font(report, header);
+            }
+            CharacterFormat::Html | CharacterFormat::Source => {
+                require_zero(report, "t_info1", header.t_info1);
+                require_zero(report, "t_info2", header.t_info2);
+                require_zero(report, "t_info3", header.t_info3);
+                require_zero(report, "t_info4", header.t_info4);
+                require_no_flags_or_font(report, header);
+            }
+            CharacterFormat::Unknown(ft) => {
+                report.push(Severity::Info, "file_type", IssueKind::UnknownFileType(ft));
+            }
+        },
+        SauceDataType::Bitmap => {
+            if header.file_type > 13 {
+                report.push(
+                    Severity::Info,
+                    "file_type",
+                    IssueKind::UnknownFileType(header.file_type),
+                );
+            }
+            require_zero(report, "t_info4", header.t_info4);
+            require_no_flags_or_font(report, header);
+        }
+        SauceDataType::Audio => {
+            let format = AudioFormat::from_sauce(header.file_type);
+            if let AudioFormat::Unknown(ft) = format {
+                report.push(Severity::Info, "file_type", IssueKind::UnknownFileType(ft));
+            }
+            if !format.has_sample_rate() {
+                require_zero(report, "t_info1", header.t_info1);
+            }
+            require_zero(report, "t_info2", header.t_info2);
+            require_zero(report, "t_info3", header.t_info3);
+            require_zero(report, "t_info4", header.t_info4);
+            require_no_flags_or_font(report, header);
+        }
+        SauceDataType::BinaryText => {
+            if header.file_type == 0 {
+                report.push(
+                    Severity::Error,
+                    "file_type",
+                    IssueKind::InvalidBinaryTextWidth(0),
+                );
+            }
+            require_zero(report, "t_info1", header.t_info1);
+            require_zero(report, "t_info2", header.t_info2);
+            require_zero(report, "t_info3", header.t_info3);
+            require_zero(report, "t_info4", header.t_info4);
+            check_ansi_flags(report, header);
+        }
+        SauceDataType::XBin => {
+            require_zero(report, "file_type", header.file_type as u16);
+            require_zero(report, "t_info3", header.t_info3);
+            require_zero(report, "t_info4", header.t_info4);
+            require_no_flags_or_font(report, header);
+        }
+        SauceDataType::Vector | SauceDataType::Archive | SauceDataType::Executable => {
+            let max_file_type = match header.data_type {
+                SauceDataType::Vector => 3,
+                SauceDataType::Archive => 9,
+                _ => 0,
+            };
+            if header.file_type > max_file_type {
+                report.push(
+                    Severity::Info,
+                    "file_type",
+                    IssueKind::UnknownFileType(header.file_type),
+                );
+            }
+            require_zero(report, "t_info1", header.t_info1);
+            require_zero(report, "t_info2", header.t_info2);
+            require_zero(report, "t_info3", header.t_info3);
+            require_zero(report, "t_info4", header.t_info4);
+            require_no_flags_or_font(report, header);
+        }
+        SauceDataType::Undefined(dt) => {
+            report.push(
+                Severity::Warning,
+                "data_type",
+                IssueKind::UnknownDataType(dt),
+            );
+        }
+    }
+}
+
+pub(crate) fn validate_header(header: &SauceHeader) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    check_text(
+        &mut report,
+        "title",
+        &header.title,
+        limits::MAX_TITLE_LENGTH,
+    );
+    check_text(
+        &mut report,
+        "author",
+        &header.author,
+        limits::MAX_AUTHOR_LENGTH,
+    );
+    check_text(
+        &mut report,
+        "group",
+        &header.group,
+        limits::MAX_GROUP_LENGTH,
+    );
+
+    let date = &header.date;
+    if !(0..10_000).contains(&date.year)
+        || !(1..=12).contains(&date.month)
+        || !(1..=31).contains(&date.day)
+    {
+        report.push(Severity::Warning, "date", IssueKind::InvalidDate);
+    }
+
+    if header.t_info_s.len() > limits::MAX_FONT_NAME_LENGTH {
+        report.push(
+            Severity::Error,
+            "t_info_s",
+            IssueKind::FieldTooLong {
+                len: header.t_info_s.len(),
+                max: limits::MAX_FONT_NAME_LENGTH,
+            },
+        );
+    }
+
+    check_type_fields(&mut report, header);
+    report
+}
+
+pub(crate) fn validate_record(record: &SauceRecord) -> ValidationReport {
+    let mut report = validate_header(&record.header);
+
+    if record.header.comments as usize != record.comments.len() {
+        report.push(
+            Severity::Error,
+            "comments",
+            IssueKind::CommentCountMismatch {
+                declared: record.header.comments,
+                actual: record.comments.len(),
+            },
+        );
+    }
+    for comment in &record.comments {
+        check_text(&mut report, "comments", comment, limits::MAX_COMMENT_LENGTH);
+    }
+    report
+}
+
+pub(crate) fn check_file_size(report: &mut ValidationReport, header: &SauceHeader, actual: u64) {
+    // 0 means "unknown" per spec and is always acceptable.
+    if header.file_size != 0 && header.file_size as u64 != actual {
+        report.push(
+            Severity::Warning,
+            "file_size",
+            IssueKind::FileSizeMismatch {
+                declared: header.file_size,
+                actual,
+            },
+        );
+    }
+}
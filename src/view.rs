@@ -0,0 +1,245 @@
+//! Borrowed, zero-copy views of SAUCE records.
+//!
+//! [`SauceRecordRef`] and [`SauceHeaderRef`] point into the input slice and decode a
+//! field only when it is accessed. Indexing many files this way avoids allocating a
+//! `BString` per field and a `Vec` of comments for every record; call
+//! [`SauceRecordRef::to_owned`] for the records worth keeping.
+//!
+//! Parsing follows [`ParseOptions::default`](crate::ParseOptions::default), so
+//! `to_owned()` gives the same record as [`SauceRecord::from_bytes`].
+//!
+//! # Example
+//!
+//! ```
+//! use icy_sauce::{SauceRecord, SauceRecordBuilder, SauceRecordRef};
+//! use bstr::BString;
+//!
+//! let mut data = b"Content".to_vec();
+//! SauceRecordBuilder::default()
+//!     .title(BString::from("Title"))?
+//!     .add_comment(BString::from("Comment"))?
+//!     .build()
+//!     .write(&mut data)?;
+//!
+//! let view = SauceRecordRef::from_bytes(&data)?.unwrap();
+//! assert_eq!(view.title(), "Title");
+//! assert_eq!(view.comments().next().unwrap(), "Comment");
+//! assert_eq!(view.to_owned(), SauceRecord::from_bytes(&data)?.unwrap());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use bstr::{BStr, BString};
+
+use crate::{
+    COMMENT_ID, COMMENT_ID_LEN, COMMENT_LEN, SauceDataType, SauceDate, SauceError, SauceRecord,
+    header::{HDR_LEN, SAUCE_ID, SauceHeader, field},
+    util::{trim_spaces_slice, zero_trim_slice},
+};
+
+/// A borrowed view of a 128-byte SAUCE header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SauceHeaderRef<'a> {
+    bytes: &'a [u8; HDR_LEN],
+}
+
+impl<'a> SauceHeaderRef<'a> {
+    /// View the SAUCE header at the end of `data`, like
+    /// [`SauceHeader::from_bytes`](crate::header::SauceHeader::from_bytes).
+    ///
+    /// # Errors
+    /// [`SauceError::UnsupportedSauceVersion`] if the version is not `"00"`.
+    pub fn from_bytes(data: &'a [u8]) -> crate::Result<Option<Self>> {
+        let Some(start) = data.len().checked_sub(HDR_LEN) else {
+            return Ok(None);
+        };
+        let bytes: &[u8; HDR_LEN] = data[start..].try_into().unwrap();
+        if &bytes[..SAUCE_ID.len()] != SAUCE_ID {
+            return Ok(None);
+        }
+        if &bytes[field::VERSION] != b"00" {
+            return Err(SauceError::UnsupportedSauceVersion(BString::from(
+                &bytes[field::VERSION],
+            )));
+        }
+        Ok(Some(Self { bytes }))
+    }
+
+    /// The underlying 128 bytes.
+    pub fn bytes(&self) -> &'a [u8; HDR_LEN] {
+        self.bytes
+    }
+
+    /// The title, without padding.
+    pub fn title(&self) -> &'a BStr {
+        BStr::new(trim_spaces_slice(&self.bytes[field::TITLE]))
+    }
+
+    /// The author, without padding.
+    pub fn author(&self) -> &'a BStr {
+        BStr::new(trim_spaces_slice(&self.bytes[field::AUTHOR]))
+    }
+
+    /// The group, without padding.
+    pub fn group(&self) -> &'a BStr {
+        BStr::new(trim_spaces_slice(&self.bytes[field::GROUP]))
+    }
+
+    /// The date; `0000/00/00` if the field is not 8 ASCII digits.
+    pub fn date(&self) -> SauceDate {
+        SauceDate::from_bytes(&self.bytes[field::DATE]).unwrap_or_default()
+    }
+
+    /// The original file size.
+    pub fn file_size(&self) -> u32 {
+        u32::from_le_bytes(self.bytes[field::FILE_SIZE].try_into().unwrap())
+    }
+
+    /// The data type.
+    pub fn data_type(&self) -> SauceDataType {
+        SauceDataType::from(self.bytes[field::DATA_TYPE])
+    }
+
+    /// The file type.
+    pub fn file_type(&self) -> u8 {
+        self.bytes[field::FILE_TYPE]
+    }
+
+    /// Type dependent numeric field 1.
+    pub fn t_info1(&self) -> u16 {
+        self.u16_at(field::T_INFO1.start)
+    }
+
+    /// Type dependent numeric field 2.
+    pub fn t_info2(&self) -> u16 {
+        self.u16_at(field::T_INFO2.start)
+    }
+
+    /// Type dependent numeric field 3.
+    pub fn t_info3(&self) -> u16 {
+        self.u16_at(field::T_INFO3.start)
+    }
+
+    /// Type dependent numeric field 4.
+    pub fn t_info4(&self) -> u16 {
+        self.u16_at(field::T_INFO4.start)
+    }
+
+    /// Number of comment lines announced by the header.
+    pub fn comments(&self) -> u8 {
+        self.bytes[field::COMMENTS]
+    }
+
+    /// Type dependent flags.
+    pub fn t_flags(&self) -> u8 {
+        self.bytes[field::T_FLAGS]
+    }
+
+    /// Type dependent string, without zero padding.
+    pub fn t_info_s(&self) -> &'a BStr {
+        BStr::new(zero_trim_slice(&self.bytes[field::T_INFO_S]))
+    }
+
+    /// Decode all fields into an owned [`SauceHeader`], like
+    /// [`SauceHeader::from_bytes`] does.
+    pub fn to_owned(&self) -> SauceHeader {
+        // `from_bytes` already checked the ID and version, the only ways the default
+        // options can reject a header
+        SauceHeader::from_bytes(self.bytes)
+            .ok()
+            .flatten()
+            .expect("view holds a valid SAUCE header")
+    }
+
+    fn u16_at(&self, offset: usize) -> u16 {
+        u16::from_le_bytes([self.bytes[offset], self.bytes[offset + 1]])
+    }
+}
+
+/// A borrowed view of a SAUCE record: header plus comment lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SauceRecordRef<'a> {
+    header: SauceHeaderRef<'a>,
+    /// The comment lines, without the `COMNT` ID; empty if there are none.
+    comment_lines: &'a [u8],
+}
+
+impl<'a> SauceRecordRef<'a> {
+    /// View the SAUCE record at the end of `data`.
+    ///
+    /// # Errors
+    /// The same errors as [`SauceRecord::from_bytes`]: an unsupported version or a comment
+    /// block that doesn't fit into `data`.
+    pub fn from_bytes(data: &'a [u8]) -> crate::Result<Option<Self>> {
+        let Some(header) = SauceHeaderRef::from_bytes(data)? else {
+            return Ok(None);
+        };
+        let mut comment_lines: &[u8] = &[];
+        let count = header.comments() as usize;
+        if count > 0 {
+            let block_len = COMMENT_ID_LEN + count * COMMENT_LEN;
+            let header_start = data.len() - HDR_LEN;
+            if header_start < block_len {
+                return Err(SauceError::InvalidCommentBlock);
+            }
+            let block = &data[header_start - block_len..header_start];
+            // Like the default parser, a block without COMNT ID is ignored
+            if block[..COMMENT_ID_LEN] == COMMENT_ID {
+                comment_lines = &block[COMMENT_ID_LEN..];
+            }
+        }
+        Ok(Some(Self {
+            header,
+            comment_lines,
+        }))
+    }
+
+    /// The header view.
+    pub fn header(&self) -> SauceHeaderRef<'a> {
+        self.header
+    }
+
+    /// The title, without padding.
+    pub fn title(&self) -> &'a BStr {
+        self.header.title()
+    }
+
+    /// The author, without padding.
+    pub fn author(&self) -> &'a BStr {
+        self.header.author()
+    }
+
+    /// The group, without padding.
+    pub fn group(&self) -> &'a BStr {
+        self.header.group()
+    }
+
+    /// The date; `0000/00/00` if the field is not 8 ASCII digits.
+    pub fn date(&self) -> SauceDate {
+        self.header.date()
+    }
+
+    /// The original file size.
+    pub fn file_size(&self) -> u32 {
+        self.header.file_size()
+    }
+
+    /// The data type.
+    pub fn data_type(&self) -> SauceDataType {
+        self.header.data_type()
+    }
+
+    /// The comment lines, without padding.
+    pub fn comments(&self) -> impl ExactSizeIterator<Item = &'a BStr> + 'a {
+        self.comment_lines
+            .chunks_exact(COMMENT_LEN)
+            .map(|line| BStr::new(trim_spaces_slice(line)))
+    }
+
+    /// Decode the whole record into an owned [`SauceRecord`].
+    pub fn to_owned(&self) -> SauceRecord {
+        SauceRecord::new(
+            self.header.to_owned(),
+            self.comments().map(BString::from).collect(),
+        )
+    }
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "tokio")]
+
+use std::{fs, io::Cursor};
+
+use bstr::BString;
+use icy_sauce::{AttachPolicy, SauceError, SauceRecord, attach_sauce};
+
+mod common;
+use common::{record, with_record};
+
+#[tokio::test]
+async fn test_from_async_reader() {
+    let data = with_record(30_000, 2);
+    let mut cursor = Cursor::new(data.clone());
+    let parsed = SauceRecord::from_async_reader(&mut cursor).await.unwrap();
+    assert_eq!(parsed, SauceRecord::from_bytes(&data).unwrap());
+    assert!(parsed.is_some());
+
+    let mut cursor = Cursor::new(b"no record".to_vec());
+    assert!(
+        SauceRecord::from_async_reader(&mut cursor)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_from_path_async() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    fs::write(&path, with_record(100, 1)).unwrap();
+    let parsed = SauceRecord::from_path_async(&path).await.unwrap().unwrap();
+    assert_eq!(parsed.title(), &BString::from("Old"));
+
+    let missing = dir.path().join("missing.ans");
+    assert!(matches!(
+        SauceRecord::from_path_async(&missing).await,
+        Err(SauceError::IoError { path, .. }) if path == missing
+    ));
+}
+
+#[tokio::test]
+async fn test_write_async() {
+    let mut out = Vec::new();
+    record("New", 1).write_async(&mut out).await.unwrap();
+    assert_eq!(out, record("New", 1).to_bytes());
+}
+
+#[tokio::test]
+async fn test_write_to_async_matches_attach() {
+    let original = with_record(20_000, 0);
+    let mut expected = original.clone();
+    attach_sauce(&mut expected, &record("New", 0), &AttachPolicy::default()).unwrap();
+
+    let mut cursor = Cursor::new(original);
+    let summary = record("New", 0)
+        .write_to_async(&mut cursor, &AttachPolicy::default())
+        .await
+        .unwrap();
+    assert_eq!(summary.new_len, expected.len() as u64);
+    assert_eq!(cursor.into_inner(), expected);
+}
+
+#[tokio::test]
+async fn test_write_to_async_refuses_shrinking_record() {
+    let original = with_record(100, 4);
+    let mut cursor = Cursor::new(original.clone());
+    let err = record("New", 0)
+        .write_to_async(&mut cursor, &AttachPolicy::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, SauceError::EditWouldShrink { .. }));
+
+    let data = cursor.into_inner();
+    assert_eq!(data, original);
+    let parsed = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert_eq!(parsed.comments().len(), 4);
+}
+
+#[tokio::test]
+async fn test_write_to_path_async_truncates() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    let original = with_record(500, 4);
+    fs::write(&path, &original).unwrap();
+
+    let mut expected = original;
+    attach_sauce(&mut expected, &record("New", 0), &AttachPolicy::default()).unwrap();
+    record("New", 0)
+        .write_to_path_async(&path, &AttachPolicy::default())
+        .await
+        .unwrap();
+    assert_eq!(fs::read(&path).unwrap(), expected);
+}
@@ -0,0 +1,123 @@
+use icy_sauce::{AttachMode, AttachPolicy, SauceRecord, StripMode, attach_sauce, strip_sauce};
+
+mod common;
+use common::record;
+
+fn titles(data: &[u8]) -> Vec<String> {
+    SauceRecord::stack_from_bytes(data)
+        .unwrap()
+        .iter()
+        .map(|l| l.record.title().to_string())
+        .collect()
+}
+
+#[test]
+fn test_attach_to_plain_data() {
+    let mut data = b"Content".to_vec();
+    let summary = attach_sauce(&mut data, &record("New", 0), &AttachPolicy::default()).unwrap();
+
+    assert_eq!(summary.records_removed, 0);
+    assert_eq!(summary.eof_bytes_removed, 0);
+    assert_eq!(summary.content_len, 7);
+    assert_eq!(summary.new_len, 7 + 1 + 128);
+    assert_eq!(summary.file_size, Some(7));
+    assert_eq!(data[7], 0x1A);
+    assert_eq!(titles(&data), ["New"]);
+}
+
+#[test]
+fn test_replace_normalizes_eof() {
+    let mut data = b"Content\x1a\x1a\x1a".to_vec();
+    record("Old", 0).write(&mut data).unwrap();
+
+    let policy = AttachPolicy {
+        normalize_eof: true,
+        ..Default::default()
+    };
+    let summary = attach_sauce(&mut data, &record("New", 0), &policy).unwrap();
+    assert_eq!(summary.records_removed, 1);
+    assert_eq!(summary.eof_bytes_removed, 4);
+    assert_eq!(strip_sauce(&data, StripMode::LastStripFinalEof), b"Content");
+    assert_eq!(titles(&data), ["New"]);
+}
+
+#[test]
+fn test_default_keeps_0x1a_content_bytes() {
+    // A BinaryText row ending in a 0x1A character/attribute pair
+    let mut data = b"A\x07\x1a\x1a".to_vec();
+    record("Old", 0).write(&mut data).unwrap();
+
+    let summary = attach_sauce(&mut data, &record("New", 0), &AttachPolicy::default()).unwrap();
+    assert_eq!(summary.eof_bytes_removed, 1);
+    assert_eq!(summary.content_len, 4);
+    assert_eq!(summary.file_size, Some(4));
+    assert_eq!(
+        strip_sauce(&data, StripMode::LastStripFinalEof),
+        b"A\x07\x1a\x1a"
+    );
+}
+
+#[test]
+fn test_replace_without_normalize_keeps_content_eof() {
+    let mut data = b"Content\x1a".to_vec();
+    record("Old", 0).write(&mut data).unwrap();
+
+    let policy = AttachPolicy {
+        normalize_eof: false,
+        fill_file_size: false,
+        ..Default::default()
+    };
+    let summary = attach_sauce(&mut data, &record("New", 0), &policy).unwrap();
+    assert_eq!(summary.eof_bytes_removed, 1);
+    assert_eq!(summary.file_size, None);
+    assert_eq!(
+        strip_sauce(&data, StripMode::LastStripFinalEof),
+        b"Content\x1a"
+    );
+    assert_eq!(
+        SauceRecord::from_bytes(&data).unwrap().unwrap().file_size(),
+        0
+    );
+}
+
+#[test]
+fn test_replace_keeps_older_stacked_records() {
+    let mut data = b"Content".to_vec();
+    record("A", 0).write(&mut data).unwrap();
+    record("B", 0).write(&mut data).unwrap();
+
+    attach_sauce(&mut data, &record("C", 0), &AttachPolicy::default()).unwrap();
+    assert_eq!(titles(&data), ["C", "A"]);
+}
+
+#[test]
+fn test_replace_all() {
+    let mut data = b"Content".to_vec();
+    record("A", 0).write(&mut data).unwrap();
+    record("B", 0).write(&mut data).unwrap();
+
+    let policy = AttachPolicy {
+        mode: AttachMode::ReplaceAll,
+        ..Default::default()
+    };
+    let summary = attach_sauce(&mut data, &record("C", 0), &policy).unwrap();
+    assert_eq!(summary.records_removed, 2);
+    assert_eq!(titles(&data), ["C"]);
+    assert_eq!(data.len(), 7 + 1 + 128);
+}
+
+#[test]
+fn test_stack() {
+    let mut data = b"Content".to_vec();
+    record("A", 0).write(&mut data).unwrap();
+
+    let policy = AttachPolicy {
+        mode: AttachMode::Stack,
+        ..Default::default()
+    };
+    let summary = attach_sauce(&mut data, &record("B", 0), &policy).unwrap();
+    assert_eq!(summary.records_removed, 0);
+    assert_eq!(summary.content_len, 7);
+    assert_eq!(summary.file_size, Some(7));
+    assert_eq!(titles(&data), ["B", "A"]);
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "std")]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use icy_sauce::{AttachPolicy, BatchEdit, Journal, SauceError, attach_sauce};
+
+mod common;
+use common::record;
+
+fn write_file(dir: &Path, name: &str, data: &[u8]) -> PathBuf {
+    let path = dir.join(name);
+    fs::write(&path, data).unwrap();
+    path
+}
+
+fn originals() -> (Vec<u8>, Vec<u8>) {
+    let mut with_record = vec![b'x'; 20_000];
+    record("Old", 0).write(&mut with_record).unwrap();
+    (b"Plain content".to_vec(), with_record)
+}
+
+#[test]
+fn test_apply_and_undo() {
+    let dir = tempfile::tempdir().unwrap();
+    let (plain, with_record) = originals();
+    let a = write_file(dir.path(), "a.ans", &plain);
+    let b = write_file(dir.path(), "b.ans", &with_record);
+    let journal_path = dir.path().join("batch.journal");
+
+    let mut batch = BatchEdit::new(AttachPolicy::default());
+    batch.add(&a, record("New", 0)).add(&b, record("New", 0));
+    assert_eq!(batch.len(), 2);
+    let journal = batch.apply(&journal_path).unwrap();
+
+    for (path, original) in [(&a, &plain), (&b, &with_record)] {
+        let mut expected = original.clone();
+        attach_sauce(&mut expected, &record("New", 0), &AttachPolicy::default()).unwrap();
+        assert_eq!(&fs::read(path).unwrap(), &expected);
+    }
+
+    // Only the replaced record is journaled, not the content before it
+    assert_eq!(journal.entries[0].original_tail, b"");
+    assert_eq!(journal.entries[1].offset, 20_000);
+    assert_eq!(journal.entries[1].original_len(), with_record.len() as u64);
+
+    let loaded = Journal::load(&journal_path).unwrap();
+    assert_eq!(loaded, journal);
+    loaded.rollback().unwrap();
+    assert_eq!(fs::read(&a).unwrap(), plain);
+    assert_eq!(fs::read(&b).unwrap(), with_record);
+
+    // Undoing twice is harmless
+    loaded.rollback().unwrap();
+    assert_eq!(fs::read(&b).unwrap(), with_record);
+}
+
+#[test]
+fn test_failed_planning_changes_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let (plain, _) = originals();
+    let a = write_file(dir.path(), "a.ans", &plain);
+    let journal_path = dir.path().join("batch.journal");
+
+    let mut batch = BatchEdit::new(AttachPolicy::default());
+    batch
+        .add(&a, record("New", 0))
+        .add(dir.path().join("missing.ans"), record("New", 0));
+    assert!(matches!(
+        batch.apply(&journal_path),
+        Err(SauceError::IoError { .. })
+    ));
+    assert_eq!(fs::read(&a).unwrap(), plain);
+    assert!(!journal_path.exists());
+}
+
+#[test]
+fn test_existing_journal_is_not_overwritten() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, with_record) = originals();
+    let b = write_file(dir.path(), "b.ans", &with_record);
+    let journal_path = dir.path().join("batch.journal");
+
+    let mut batch = BatchEdit::new(AttachPolicy::default());
+    batch.add(&b, record("New", 0));
+    let journal = batch.apply(&journal_path).unwrap();
+    let edited = fs::read(&b).unwrap();
+
+    let mut batch = BatchEdit::new(AttachPolicy::default());
+    batch.add(&b, record("Later", 0));
+    assert!(matches!(
+        batch.apply(&journal_path),
+        Err(SauceError::IoError { .. })
+    ));
+    assert_eq!(fs::read(&b).unwrap(), edited);
+    assert_eq!(Journal::load(&journal_path).unwrap(), journal);
+}
+
+#[test]
+fn test_failed_edit_rolls_back() {
+    let dir = tempfile::tempdir().unwrap();
+    let (plain, with_record) = originals();
+    let a = write_file(dir.path(), "a.ans", &plain);
+    let b = write_file(dir.path(), "b.ans", &with_record);
+    let journal_path = dir.path().join("batch.journal");
+
+    // The second edit of `a` was planned against the original file and is stale once
+    // the first edit has been applied.
+    let mut batch = BatchEdit::new(AttachPolicy::default());
+    batch
+        .add(&b, record("New", 0))
+        .add(&a, record("First", 0))
+        .add(&a, record("Second", 0));
+    assert!(matches!(
+        batch.apply(&journal_path),
+        Err(SauceError::StaleEditPlan { .. })
+    ));
+    assert_eq!(fs::read(&a).unwrap(), plain);
+    assert_eq!(fs::read(&b).unwrap(), with_record);
+}
+
+#[test]
+fn test_rollback_refuses_file_edited_again() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, with_record) = originals();
+    let b = write_file(dir.path(), "b.ans", &with_record);
+    let journal_path = dir.path().join("batch.journal");
+
+    let mut batch = BatchEdit::new(AttachPolicy::default());
+    batch.add(&b, record("New", 0));
+    let journal = batch.apply(&journal_path).unwrap();
+
+    let mut edited_again = fs::read(&b).unwrap();
+    attach_sauce(
+        &mut edited_again,
+        &record("Later", 0),
+        &AttachPolicy::default(),
+    )
+    .unwrap();
+    edited_again.extend(b" and more");
+    fs::write(&b, &edited_again).unwrap();
+
+    assert!(matches!(
+        journal.rollback(),
+        Err(SauceError::InvalidJournal(_))
+    ));
+    assert_eq!(fs::read(&b).unwrap(), edited_again);
+}
+
+#[test]
+fn test_journal_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let (_, with_record) = originals();
+    let b = write_file(dir.path(), "b.ans", &with_record);
+    let journal_path = dir.path().join("batch.journal");
+
+    let mut batch = BatchEdit::new(AttachPolicy::default());
+    batch.add(&b, record("New", 0));
+    let journal = batch.apply(&journal_path).unwrap();
+
+    let text = journal.serialize().unwrap();
+    assert!(text.starts_with("SAUCE-BATCH-JOURNAL 1\n"));
+    assert_eq!(Journal::parse(&text).unwrap(), journal);
+}
+
+#[test]
+fn test_invalid_journal() {
+    assert!(matches!(
+        Journal::parse("not a journal"),
+        Err(SauceError::InvalidJournal(_))
+    ));
+    assert!(matches!(
+        Journal::parse("SAUCE-BATCH-JOURNAL 1\nfile a.ans\noffset 1\n"),
+        Err(SauceError::InvalidJournal(_))
+    ));
+    assert!(matches!(
+        Journal::parse("SAUCE-BATCH-JOURNAL 1\nfile a.ans\noffset 1\nnew-len 2\ntail 1g\n"),
+        Err(SauceError::InvalidJournal(_))
+    ));
+}
@@ -0,0 +1,67 @@
+use icy_sauce::{ByteSink, SauceError, SauceRecord};
+
+mod common;
+use common::record;
+
+/// A fixed-size buffer, like a `no_std` target would write into.
+struct FixedSink {
+    buf: [u8; 256],
+    len: usize,
+}
+
+impl FixedSink {
+    fn new() -> Self {
+        Self {
+            buf: [0; 256],
+            len: 0,
+        }
+    }
+}
+
+impl ByteSink for FixedSink {
+    fn write_bytes(&mut self, buf: &[u8]) -> icy_sauce::Result<()> {
+        let end = self.len + buf.len();
+        if end > self.buf.len() {
+            return Err(SauceError::WriteFailed("sink full".into()));
+        }
+        self.buf[self.len..end].copy_from_slice(buf);
+        self.len = end;
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_sink_receives_record() {
+    let record = record("Title", 1);
+    let mut sink = FixedSink::new();
+    record.write(&mut sink).unwrap();
+    assert_eq!(&sink.buf[..sink.len], record.to_bytes().as_slice());
+    let parsed = SauceRecord::from_bytes(&sink.buf[..sink.len])
+        .unwrap()
+        .unwrap();
+    assert_eq!(parsed, record);
+}
+
+#[test]
+fn custom_sink_error_is_returned() {
+    let mut sink = FixedSink::new();
+    let err = record("Title", 3).write(&mut sink).unwrap_err();
+    assert!(matches!(err, SauceError::WriteFailed(_)));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_write_is_a_sink() {
+    let record = record("Title", 0);
+    let mut out = icy_sauce::IoSink(std::io::Cursor::new(Vec::new()));
+    record.write(&mut out).unwrap();
+    assert_eq!(out.0.into_inner(), record.to_bytes());
+}
+
+#[test]
+fn vec_and_references_are_sinks() {
+    let record = record("Title", 1);
+    let mut out = Vec::new();
+    record.write(&mut &mut out).unwrap();
+    assert_eq!(out, record.to_bytes());
+}
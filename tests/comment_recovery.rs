@@ -0,0 +1,108 @@
+use bstr::BString;
+use icy_sauce::{DiagnosticKind, ParseOptions, SauceError, SauceRecord, SauceRecordBuilder};
+
+const COUNT_POS: usize = 104;
+
+fn record_with_comments(count: usize) -> Vec<u8> {
+    let mut data = b"Content".to_vec();
+    let mut builder = SauceRecordBuilder::default();
+    for i in 0..count {
+        builder = builder
+            .add_comment(BString::from(format!("Line {i}")))
+            .unwrap();
+    }
+    builder.build().write(&mut data).unwrap();
+    data
+}
+
+fn set_count(data: &mut [u8], count: u8) {
+    let pos = data.len() - 128 + COUNT_POS;
+    data[pos] = count;
+}
+
+fn scanning() -> ParseOptions {
+    ParseOptions {
+        scan_for_comments: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_count_zero_with_block() {
+    let mut data = record_with_comments(3);
+    set_count(&mut data, 0);
+
+    // Without scanning the block is invisible
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert!(record.comments().is_empty());
+
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &scanning()).unwrap();
+    let record = record.unwrap();
+    assert_eq!(record.comments().len(), 3);
+    assert_eq!(record.header().comments, 3);
+    assert_eq!(record.comments()[2], BString::from("Line 2"));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::CommentCountMismatch);
+    assert_eq!(diagnostics[0].offset, 8);
+}
+
+#[test]
+fn test_count_one_too_low() {
+    let mut data = record_with_comments(2);
+    set_count(&mut data, 1);
+
+    let record = SauceRecord::from_bytes_with_options(&data, &scanning())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.comments().len(), 2);
+    assert_eq!(record.comments()[0], BString::from("Line 0"));
+}
+
+#[test]
+fn test_count_one_too_high() {
+    let mut data = record_with_comments(2);
+    set_count(&mut data, 3);
+
+    // 3 lines would reach past the start of the data
+    assert!(matches!(
+        SauceRecord::from_bytes(&data),
+        Err(SauceError::InvalidCommentBlock)
+    ));
+
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &scanning()).unwrap();
+    assert_eq!(record.unwrap().comments().len(), 2);
+    // The EOF marker is found at the corrected position
+    assert!(
+        diagnostics
+            .iter()
+            .all(|d| d.kind == DiagnosticKind::CommentCountMismatch)
+    );
+}
+
+#[test]
+fn test_correct_count_is_not_scanned() {
+    let data = record_with_comments(2);
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &scanning()).unwrap();
+    assert_eq!(record.unwrap().comments().len(), 2);
+    assert!(diagnostics.is_empty());
+
+    let data = record_with_comments(0);
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &scanning()).unwrap();
+    assert!(record.unwrap().comments().is_empty());
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_lenient_scans() {
+    let mut data = record_with_comments(4);
+    set_count(&mut data, 2);
+    let record = SauceRecord::from_bytes_with_options(&data, &ParseOptions::lenient())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.comments().len(), 4);
+}
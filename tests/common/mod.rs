@@ -0,0 +1,27 @@
+//! Fixtures shared by the integration tests.
+
+// Every test crate uses only some of these
+#![allow(dead_code)]
+
+use bstr::BString;
+use icy_sauce::{SauceRecord, SauceRecordBuilder};
+
+/// A record titled `title` with `comments` comment lines.
+pub fn record(title: &str, comments: usize) -> SauceRecord {
+    let mut builder = SauceRecordBuilder::default()
+        .title(BString::from(title))
+        .unwrap();
+    for i in 0..comments {
+        builder = builder
+            .add_comment(BString::from(format!("Comment {i}")))
+            .unwrap();
+    }
+    builder.build()
+}
+
+/// `content_len` bytes of content followed by an EOF marker and a record titled "Old".
+pub fn with_record(content_len: usize, comments: usize) -> Vec<u8> {
+    let mut data = vec![b'x'; content_len];
+    record("Old", comments).write(&mut data).unwrap();
+    data
+}
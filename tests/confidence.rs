@@ -0,0 +1,130 @@
+use bstr::BString;
+use icy_sauce::{
+    ConfidenceCheck, DiagnosticKind, ParseOptions, SauceDate, SauceRecord, SauceRecordBuilder,
+};
+
+fn genuine() -> Vec<u8> {
+    let mut data = b"Content".to_vec();
+    SauceRecordBuilder::default()
+        .title(BString::from("Title"))
+        .unwrap()
+        .date(SauceDate::new(2025, 11, 8))
+        .file_size(7)
+        .build()
+        .write(&mut data)
+        .unwrap();
+    data
+}
+
+/// Documentation that happens to end with a SAUCE signature and 121 bytes of text.
+fn false_positive() -> Vec<u8> {
+    let mut data = b"Some text about the format.\r\n".repeat(300);
+    data.extend(b"SAUCE00");
+    let mut text = b"The title follows the id and is 35 chars. Then author, group and date, \
+          e.g. 20250101, and many other details about the header fields.\r\n"
+        .to_vec();
+    text.resize(121, b' ');
+    data.extend(text);
+    data
+}
+
+#[test]
+fn test_genuine_record_scores_full() {
+    let data = genuine();
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    let confidence = record.confidence(&data);
+    assert_eq!(confidence.score, 100);
+    assert!(confidence.failed.is_empty());
+}
+
+#[test]
+fn test_false_positive_scores_low() {
+    let data = false_positive();
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    let confidence = record.confidence(&data);
+    assert!(confidence.score < 50, "score {}", confidence.score);
+    assert!(!confidence.passed(ConfidenceCheck::DateDigits));
+    assert!(!confidence.passed(ConfidenceCheck::EofMarker));
+    assert!(confidence.passed(ConfidenceCheck::PrintableMetadata));
+}
+
+#[test]
+fn test_individual_checks() {
+    let mut data = genuine();
+    let h = data.len() - 128;
+    data[h + 7] = 0x07; // bell in the title
+    data[h + 94] = 42; // unknown data type
+    data[h + 90] = 99; // file size mismatch
+
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    let confidence = record.confidence(&data);
+    assert_eq!(
+        confidence.failed,
+        [
+            ConfidenceCheck::KnownDataType,
+            ConfidenceCheck::PlausibleFileSize,
+            ConfidenceCheck::PrintableMetadata
+        ]
+    );
+    assert_eq!(confidence.score, 25 + 15);
+}
+
+#[test]
+fn test_zero_file_size_is_plausible() {
+    let mut data = genuine();
+    let h = data.len() - 128;
+    data[h + 90..h + 94].fill(0);
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert!(
+        record
+            .confidence(&data)
+            .passed(ConfidenceCheck::PlausibleFileSize)
+    );
+}
+
+#[test]
+fn test_min_confidence_rejects_false_positive() {
+    let options = ParseOptions {
+        min_confidence: 50,
+        ..Default::default()
+    };
+    let data = false_positive();
+    assert!(SauceRecord::from_bytes(&data).unwrap().is_some());
+    assert!(
+        SauceRecord::from_bytes_with_options(&data, &options)
+            .unwrap()
+            .is_none()
+    );
+
+    let (record, diagnostics) = SauceRecord::from_bytes_with_diagnostics(&data, &options).unwrap();
+    assert!(record.is_none());
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.kind == DiagnosticKind::LowConfidence && d.offset == 29 * 300)
+    );
+
+    assert!(
+        SauceRecord::from_bytes_with_options(&genuine(), &options)
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[test]
+fn test_scan_skips_false_positive() {
+    let mut data = false_positive();
+    data.extend([0u8; 16]);
+    assert!(SauceRecord::find_in(&data).is_none());
+
+    let mut data = genuine();
+    data.extend([0u8; 16]);
+    assert_eq!(SauceRecord::find_in(&data).unwrap().confidence.score, 100);
+}
+
+#[test]
+fn test_stack_reports_confidence() {
+    let data = genuine();
+    let stack = SauceRecord::stack_from_bytes(&data).unwrap();
+    assert_eq!(stack[0].confidence.score, 100);
+}
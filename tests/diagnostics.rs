@@ -0,0 +1,120 @@
+use bstr::BString;
+use icy_sauce::{DiagnosticKind, ParseOptions, SauceDate, SauceRecord, SauceRecordBuilder};
+
+fn record_with_comments() -> Vec<u8> {
+    let mut data = b"Content".to_vec();
+    SauceRecordBuilder::default()
+        .title(BString::from("Title"))
+        .unwrap()
+        .date(SauceDate::new(2025, 11, 8))
+        .add_comment(BString::from("First"))
+        .unwrap()
+        .build()
+        .write(&mut data)
+        .unwrap();
+    data
+}
+
+#[test]
+fn test_clean_record_has_no_diagnostics() {
+    let data = record_with_comments();
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::default()).unwrap();
+    assert!(record.is_some());
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_no_sauce_has_no_diagnostics() {
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(b"plain text", &ParseOptions::default()).unwrap();
+    assert!(record.is_none());
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_missing_eof_marker() {
+    let mut data = record_with_comments();
+    let eof_pos = data.len() - 128 - 64 - 5 - 1;
+    data.remove(eof_pos);
+
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::default()).unwrap();
+    assert!(record.is_some());
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::MissingEofMarker);
+    assert_eq!(diagnostics[0].offset, eof_pos - 1);
+}
+
+#[test]
+fn test_missing_comment_id() {
+    let mut data = record_with_comments();
+    let block_start = data.len() - 128 - 64 - 5;
+    data[block_start..block_start + 5].copy_from_slice(b"XXXXX");
+
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::default()).unwrap();
+    assert!(record.unwrap().comments().is_empty());
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::MissingCommentId);
+    assert_eq!(diagnostics[0].offset, block_start);
+
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::lenient()).unwrap();
+    assert_eq!(record.unwrap().comments().len(), 1);
+    assert_eq!(diagnostics[0].kind, DiagnosticKind::MissingCommentId);
+}
+
+#[test]
+fn test_truncated_comment_block() {
+    let data = record_with_comments();
+    let truncated = &data[data.len() - 128 - 10..];
+
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(truncated, &ParseOptions::lenient()).unwrap();
+    assert!(record.unwrap().comments().is_empty());
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.kind == DiagnosticKind::TruncatedCommentBlock)
+    );
+
+    // Still an error with the default options
+    assert!(SauceRecord::from_bytes_with_diagnostics(truncated, &ParseOptions::default()).is_err());
+}
+
+#[test]
+fn test_invalid_date_and_unknown_version() {
+    let mut data = record_with_comments();
+    let header_start = data.len() - 128;
+    data[header_start + 5..header_start + 7].copy_from_slice(b"01");
+    data[header_start + 82..header_start + 90].copy_from_slice(b"2025-11-");
+
+    let (record, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::lenient()).unwrap();
+    assert_eq!(record.unwrap().date(), SauceDate::default());
+
+    let kinds: Vec<_> = diagnostics.iter().map(|d| (d.kind, d.offset)).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (DiagnosticKind::UnknownVersion, header_start + 5),
+            (DiagnosticKind::InvalidDate, header_start + 82),
+        ]
+    );
+}
+
+#[test]
+fn test_display_includes_offset() {
+    let mut data = b"Content".to_vec();
+    SauceRecordBuilder::default()
+        .build()
+        .write_without_eof(&mut data)
+        .unwrap();
+    let (_, diagnostics) =
+        SauceRecord::from_bytes_with_diagnostics(&data, &ParseOptions::default()).unwrap();
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "offset 6: Missing EOF marker before SAUCE record"
+    );
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "std")]
+
+use std::{fs, path::Path};
+
+use bstr::BString;
+use icy_sauce::{AttachMode, AttachPolicy, SauceRecord, StripMode};
+
+mod common;
+use common::record;
+
+fn write_file(dir: &Path, data: &[u8]) -> std::path::PathBuf {
+    let path = dir.join("art.bin");
+    fs::write(&path, data).unwrap();
+    path
+}
+
+fn large_content() -> Vec<u8> {
+    (0..100_000u32).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn test_write_to_plain_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let content = large_content();
+    let path = write_file(dir.path(), &content);
+
+    let summary = record("New", 2)
+        .write_to_path(&path, &AttachPolicy::default())
+        .unwrap();
+    assert_eq!(summary.content_len, content.len() as u64);
+    assert_eq!(summary.file_size, Some(content.len() as u32));
+
+    let data = fs::read(&path).unwrap();
+    assert_eq!(data.len() as u64, summary.new_len);
+    assert_eq!(&data[..content.len()], &content[..]);
+    let parsed = SauceRecord::from_path(&path).unwrap().unwrap();
+    assert_eq!(parsed.title(), &BString::from("New"));
+    assert_eq!(parsed.comments().len(), 2);
+}
+
+#[test]
+fn test_replace_matches_in_memory_attach() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut data = large_content();
+    record("Old", 5).write(&mut data).unwrap();
+    let path = write_file(dir.path(), &data);
+
+    // Shrinking record
+    let new = record("New", 1);
+    let on_disk = new.write_to_path(&path, &AttachPolicy::default()).unwrap();
+    let in_memory = icy_sauce::attach_sauce(&mut data, &new, &AttachPolicy::default()).unwrap();
+    assert_eq!(on_disk, in_memory);
+    assert_eq!(fs::read(&path).unwrap(), data);
+
+    // Growing record
+    let new = record("Newer", 10);
+    new.write_to_path(&path, &AttachPolicy::default()).unwrap();
+    icy_sauce::attach_sauce(&mut data, &new, &AttachPolicy::default()).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), data);
+}
+
+#[test]
+fn test_replace_all_with_stack_larger_than_one_window() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut data = large_content();
+    for i in 0..4 {
+        record(&format!("R{i}"), 255).write(&mut data).unwrap();
+    }
+    let path = write_file(dir.path(), &data);
+
+    let policy = AttachPolicy {
+        mode: AttachMode::ReplaceAll,
+        ..Default::default()
+    };
+    let summary = record("Only", 0).write_to_path(&path, &policy).unwrap();
+    assert_eq!(summary.records_removed, 4);
+    assert_eq!(summary.new_len, 100_000 + 1 + 128);
+    assert_eq!(
+        SauceRecord::stack_from_bytes(&fs::read(&path).unwrap())
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_remove_from_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let content = large_content();
+    let mut data = content.clone();
+    record("A", 3).write(&mut data).unwrap();
+    record("B", 0).write(&mut data).unwrap();
+    let path = write_file(dir.path(), &data);
+
+    let summary = SauceRecord::remove_from_path(&path, StripMode::LastStripFinalEof).unwrap();
+    assert_eq!(summary.records_removed, 1);
+    assert_eq!(summary.eof_bytes_removed, 1);
+    assert_eq!(summary.content_len, content.len() as u64);
+    assert_eq!(
+        SauceRecord::from_path(&path).unwrap().unwrap().title(),
+        &BString::from("A")
+    );
+
+    SauceRecord::remove_from_path(&path, StripMode::AllStripFinalEof).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), content);
+
+    // Nothing left to remove
+    let summary = SauceRecord::remove_from_path(&path, StripMode::All).unwrap();
+    assert_eq!(summary.records_removed, 0);
+    assert_eq!(summary.new_len, content.len() as u64);
+}
+
+#[test]
+fn test_small_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_file(dir.path(), b"");
+    record("T", 0)
+        .write_to_path(&path, &AttachPolicy::default())
+        .unwrap();
+    assert_eq!(fs::read(&path).unwrap().len(), 129);
+    SauceRecord::remove_from_path(&path, StripMode::default()).unwrap();
+    assert!(fs::read(&path).unwrap().is_empty());
+}
+
+#[test]
+fn test_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing");
+    assert!(
+        record("T", 0)
+            .write_to_path(&path, &AttachPolicy::default())
+            .is_err()
+    );
+    assert!(SauceRecord::remove_from_path(&path, StripMode::default()).is_err());
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "std")]
+
+use std::{
+    fs,
+    time::{Duration, SystemTime},
+};
+
+use icy_sauce::{AttachPolicy, SauceError, SauceRecord, StripMode, attach_sauce};
+
+mod common;
+use common::record;
+
+#[test]
+fn test_atomic_write_matches_in_memory_attach() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    let mut data = b"Content".repeat(5000);
+    record("Old", 1).write(&mut data).unwrap();
+    fs::write(&path, &data).unwrap();
+
+    let new = record("New", 1);
+    let summary = new
+        .write_to_path_atomic(&path, &AttachPolicy::default())
+        .unwrap();
+    attach_sauce(&mut data, &new, &AttachPolicy::default()).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), data);
+    assert_eq!(summary.new_len, data.len() as u64);
+
+    // No temporary files are left behind
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+#[test]
+fn test_atomic_temp_files_do_not_collide() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    fs::write(&path, b"Content").unwrap();
+    // Leftover of a crashed run that had the same pid
+    let leftover = dir
+        .path()
+        .join(format!(".art.ans.sauce-tmp-{}", std::process::id()));
+    fs::write(&leftover, b"").unwrap();
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..10 {
+                    // Concurrent edits may see each other's result, but never fail on
+                    // the temporary file
+                    match record("New", 0).write_to_path_atomic(&path, &AttachPolicy::default()) {
+                        Ok(_) | Err(SauceError::StaleEditPlan { .. }) => {}
+                        Err(err) => panic!("{err}"),
+                    }
+                }
+            });
+        }
+    });
+    let data = fs::read(&path).unwrap();
+    let parsed = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert_eq!(parsed.title(), "New");
+    assert_eq!(parsed.file_size(), 7);
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+}
+
+#[test]
+fn test_atomic_preserves_times() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    fs::write(&path, b"Content").unwrap();
+
+    let old = SystemTime::UNIX_EPOCH + Duration::from_secs(820_454_400); // 1996-01-01
+    let file = fs::File::options().write(true).open(&path).unwrap();
+    file.set_times(fs::FileTimes::new().set_modified(old).set_accessed(old))
+        .unwrap();
+    drop(file);
+
+    record("New", 1)
+        .write_to_path_atomic(&path, &AttachPolicy::default())
+        .unwrap();
+    let metadata = fs::metadata(&path).unwrap();
+    assert_eq!(metadata.modified().unwrap(), old);
+    assert!(SauceRecord::from_path(&path).unwrap().is_some());
+}
+
+#[cfg(unix)]
+#[test]
+fn test_atomic_preserves_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    fs::write(&path, b"Content").unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+    record("New", 1)
+        .write_to_path_atomic(&path, &AttachPolicy::default())
+        .unwrap();
+    let mode = fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o640);
+}
+
+#[test]
+fn test_atomic_remove() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    let mut data = b"Content".to_vec();
+    record("Old", 1).write(&mut data).unwrap();
+    fs::write(&path, &data).unwrap();
+
+    let summary = SauceRecord::remove_from_path_atomic(&path, StripMode::default()).unwrap();
+    assert_eq!(summary.records_removed, 1);
+    assert_eq!(fs::read(&path).unwrap(), b"Content");
+
+    let summary = SauceRecord::remove_from_path_atomic(&path, StripMode::default()).unwrap();
+    assert_eq!(summary.records_removed, 0);
+    assert_eq!(fs::read(&path).unwrap(), b"Content");
+}
+
+#[test]
+fn test_atomic_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("missing");
+    assert!(
+        record("New", 1)
+            .write_to_path_atomic(&path, &AttachPolicy::default())
+            .is_err()
+    );
+    assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+}
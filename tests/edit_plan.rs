@@ -0,0 +1,155 @@
+use icy_sauce::{AttachPolicy, EditOp, EditPlan, SauceError, StripMode, attach_sauce};
+
+mod common;
+use common::record;
+
+/// Also drops the content's trailing 0x1A bytes, so the plans truncate them.
+fn normalizing() -> AttachPolicy {
+    AttachPolicy {
+        normalize_eof: true,
+        ..Default::default()
+    }
+}
+
+fn with_record() -> Vec<u8> {
+    let mut data = b"Content\x1a\x1a".to_vec();
+    record("Old", 0).write(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn test_plan_describes_replacement() {
+    let data = with_record();
+    let plan = EditPlan::for_bytes(&data, &record("New", 0), &normalizing());
+
+    assert_eq!(plan.original_len, data.len() as u64);
+    assert_eq!(plan.ops.len(), 3);
+    assert_eq!(plan.ops[0], EditOp::Truncate { at: 7 });
+    assert_eq!(plan.ops[1], EditOp::WriteEof);
+    assert!(matches!(&plan.ops[2], EditOp::Write { bytes } if bytes.len() == 128));
+    assert_eq!(plan.new_len(), 7 + 1 + 128);
+}
+
+#[test]
+fn test_plan_matches_attach() {
+    let mut expected = with_record();
+    let mut data = expected.clone();
+    let plan = EditPlan::for_bytes(&data, &record("New", 0), &normalizing());
+    plan.apply_to_vec(&mut data).unwrap();
+    attach_sauce(&mut expected, &record("New", 0), &normalizing()).unwrap();
+    assert_eq!(data, expected);
+}
+
+#[test]
+fn test_remove_plan() {
+    let mut data = with_record();
+    let plan = EditPlan::remove_for_bytes(&data, StripMode::LastStripFinalEof);
+    assert_eq!(plan.ops, [EditOp::Truncate { at: 9 }]);
+    plan.apply_to_vec(&mut data).unwrap();
+    assert_eq!(data, b"Content\x1a\x1a");
+
+    let plan = EditPlan::remove_for_bytes(b"plain", StripMode::default());
+    assert!(plan.is_empty());
+}
+
+#[test]
+fn test_display() {
+    let data = with_record();
+    let plan = EditPlan::for_bytes(&data, &record("New", 0), &normalizing());
+    let text = plan.to_string();
+    assert!(text.starts_with(&format!("original length: {} bytes\n", data.len())));
+    assert!(text.contains("truncate at 7\n"));
+    assert!(text.contains("write EOF marker (0x1A)\n"));
+    assert!(text.contains("write 128 bytes \"SAUCE00New"));
+    assert!(text.ends_with("new length: 136 bytes"));
+}
+
+#[test]
+fn test_serialize_round_trip() {
+    let data = with_record();
+    let plan = EditPlan::for_bytes(&data, &record("New", 0), &normalizing());
+    let text = plan.serialize();
+    assert!(text.starts_with(
+        "SAUCE-EDIT-PLAN 1\noriginal-len 138\ntruncate 7\nwrite-eof\nwrite 5341554345"
+    ));
+    assert_eq!(EditPlan::parse(&text).unwrap(), plan);
+}
+
+#[test]
+fn test_parse_errors() {
+    for text in [
+        "",
+        "original-len 5",
+        "SAUCE-EDIT-PLAN 1\n",
+        "SAUCE-EDIT-PLAN 1\noriginal-len x",
+        "SAUCE-EDIT-PLAN 1\noriginal-len 5\ndelete 3",
+        "SAUCE-EDIT-PLAN 1\noriginal-len 5\nwrite abc",
+        "SAUCE-EDIT-PLAN 1\noriginal-len 5\nwrite zz",
+    ] {
+        assert!(
+            matches!(EditPlan::parse(text), Err(SauceError::InvalidEditPlan(_))),
+            "{text:?}"
+        );
+    }
+}
+
+#[test]
+fn test_stale_plan_is_rejected() {
+    let data = with_record();
+    let plan = EditPlan::for_bytes(&data, &record("New", 0), &normalizing());
+    let mut other = b"Different".to_vec();
+    assert!(matches!(
+        plan.apply_to_vec(&mut other),
+        Err(SauceError::StaleEditPlan {
+            expected: 138,
+            actual: 9
+        })
+    ));
+    assert_eq!(other, b"Different");
+}
+
+#[test]
+fn test_truncate_beyond_end_is_invalid() {
+    let plan = EditPlan {
+        original_len: 3,
+        ops: vec![EditOp::Truncate { at: 10 }],
+    };
+    assert!(matches!(
+        plan.apply_to_vec(&mut b"abc".to_vec()),
+        Err(SauceError::InvalidEditPlan(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_path_plan() {
+    use icy_sauce::SauceRecord;
+    use std::fs;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    let data = with_record();
+    fs::write(&path, &data).unwrap();
+
+    let plan = EditPlan::for_path(&path, &record("New", 0), &normalizing()).unwrap();
+    assert_eq!(
+        plan,
+        EditPlan::for_bytes(&data, &record("New", 0), &normalizing())
+    );
+    // Nothing happens until the plan is applied
+    assert_eq!(fs::read(&path).unwrap(), data);
+
+    EditPlan::parse(&plan.serialize())
+        .unwrap()
+        .apply_to_path(&path)
+        .unwrap();
+    let parsed = SauceRecord::from_path(&path).unwrap().unwrap();
+    assert_eq!(parsed.title(), "New");
+
+    // The plan is stale now
+    assert!(plan.apply_to_path(&path).is_err());
+
+    let plan = EditPlan::remove_for_path(&path, StripMode::default()).unwrap();
+    plan.apply_to_path_atomic(&path).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"Content");
+}
@@ -0,0 +1,87 @@
+use bstr::BString;
+use icy_sauce::{SauceDataType, SauceRecord, SauceRecordBuilder, limits};
+
+fn with_record(comments: usize) -> Vec<u8> {
+    let mut data = b"Content".to_vec();
+    let mut builder = SauceRecordBuilder::default()
+        .title(BString::from("Found"))
+        .unwrap();
+    for _ in 0..comments {
+        builder = builder.add_comment(BString::from("Comment")).unwrap();
+    }
+    builder.build().write(&mut data).unwrap();
+    data
+}
+
+#[test]
+fn test_record_at_end() {
+    let data = with_record(0);
+    let found = SauceRecord::find_in(&data).unwrap();
+    assert_eq!(found.trailing, 0);
+    assert_eq!(found.range, 8..data.len());
+    assert!(found.eof_marker);
+}
+
+#[test]
+fn test_xmodem_padding() {
+    let mut data = with_record(0);
+    let len = data.len();
+    data.resize(len.next_multiple_of(128), 0x1A);
+    let found = SauceRecord::find_in(&data).unwrap();
+    assert_eq!(found.trailing, data.len() - len);
+    assert_eq!(found.record.title(), &BString::from("Found"));
+}
+
+#[test]
+fn test_nul_padding_with_comments() {
+    let mut data = with_record(2);
+    data.extend([0u8; 300]);
+    let found = SauceRecord::find_in(&data).unwrap();
+    assert_eq!(found.trailing, 300);
+    assert_eq!(found.range, 8..8 + 5 + 2 * 64 + 128);
+    assert_eq!(found.record.comments().len(), 2);
+}
+
+#[test]
+fn test_appended_banner() {
+    let mut data = with_record(0);
+    data.extend(b"\r\nDownloaded from The Best BBS - call now!\r\n");
+    assert!(SauceRecord::find_in(&data).is_some());
+}
+
+#[test]
+fn test_window_limit() {
+    let mut data = with_record(0);
+    data.extend(vec![0u8; limits::DEFAULT_SCAN_WINDOW]);
+    assert!(SauceRecord::find_in(&data).is_none());
+    assert!(SauceRecord::find_in_window(&data, limits::DEFAULT_SCAN_WINDOW + 128).is_some());
+    assert!(SauceRecord::find_in_window(&data, limits::DEFAULT_SCAN_WINDOW + 127).is_none());
+}
+
+#[test]
+fn test_rejects_implausible_candidate() {
+    // Text that mentions the signature is not a record
+    let mut data = b"The header starts with SAUCE00 followed by the title.".to_vec();
+    data.extend([b' '; 200]);
+    assert!(SauceRecord::find_in(&data).is_none());
+
+    // Undefined data type
+    let mut data = with_record(0);
+    let data_type = data.len() - 128 + 94;
+    data[data_type] = 200;
+    data.extend([0u8; 10]);
+    assert!(SauceRecord::find_in(&data).is_none());
+}
+
+#[test]
+fn test_prefers_record_nearest_to_end() {
+    let mut data = with_record(0);
+    SauceRecordBuilder::default()
+        .data_type(SauceDataType::Bitmap)
+        .build()
+        .write(&mut data)
+        .unwrap();
+    data.extend([0u8; 5]);
+    let found = SauceRecord::find_in(&data).unwrap();
+    assert_eq!(found.record.data_type(), SauceDataType::Bitmap);
+}
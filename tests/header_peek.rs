@@ -0,0 +1,116 @@
+#![cfg(feature = "std")]
+
+use std::{
+    fs,
+    io::{Cursor, Read, Seek, SeekFrom},
+};
+
+use icy_sauce::{ParseOptions, SauceRecord, SauceRecordBuilder, header::SauceHeader};
+
+mod common;
+use common::with_record;
+
+/// Counts the bytes read through it.
+struct CountingReader {
+    inner: Cursor<Vec<u8>>,
+    read: usize,
+}
+
+impl CountingReader {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            inner: Cursor::new(data),
+            read: 0,
+        }
+    }
+}
+
+impl Read for CountingReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.read += n;
+        Ok(n)
+    }
+}
+
+impl Seek for CountingReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+fn bytes_read(data: Vec<u8>, options: &ParseOptions) -> (Option<SauceRecord>, usize) {
+    let mut reader = CountingReader::new(data);
+    let record = SauceRecord::from_reader_with_options(&mut reader, options).unwrap();
+    assert_eq!(reader.inner.position(), reader.inner.get_ref().len() as u64);
+    (record, reader.read)
+}
+
+#[test]
+fn test_header_only_read_without_comments() {
+    let data = with_record(100_000, 0);
+    let (record, read) = bytes_read(data.clone(), &ParseOptions::default());
+    assert_eq!(record, SauceRecord::from_bytes(&data).unwrap());
+    assert_eq!(read, 129);
+
+    let (record, read) = bytes_read(vec![b'x'; 100_000], &ParseOptions::default());
+    assert!(record.is_none());
+    assert_eq!(read, 129);
+}
+
+#[test]
+fn test_comment_block_read_on_demand() {
+    let data = with_record(100_000, 3);
+    let (record, read) = bytes_read(data.clone(), &ParseOptions::default());
+    assert_eq!(record.as_ref().unwrap().comments().len(), 3);
+    assert_eq!(record, SauceRecord::from_bytes(&data).unwrap());
+    assert_eq!(read, 1 + 5 + 3 * 64 + 128);
+}
+
+#[test]
+fn test_comment_scan_reads_full_window() {
+    let mut data = with_record(100_000, 2);
+    // Header claims no comments; lenient parsing recovers the block
+    let count = data.len() - 128 + 104;
+    data[count] = 0;
+    let (record, read) = bytes_read(data.clone(), &ParseOptions::lenient());
+    assert_eq!(record.unwrap().comments().len(), 2);
+    assert_eq!(read, icy_sauce::limits::MAX_RECORD_LEN);
+}
+
+#[test]
+fn test_short_streams() {
+    for len in [0, 1, 128, 129] {
+        let (record, read) = bytes_read(vec![b'x'; len], &ParseOptions::default());
+        assert!(record.is_none());
+        assert_eq!(read, len);
+    }
+    let mut data = Vec::new();
+    SauceRecordBuilder::default()
+        .build()
+        .write_without_eof(&mut data)
+        .unwrap();
+    let (record, _) = bytes_read(data, &ParseOptions::default());
+    assert!(record.is_some());
+}
+
+#[test]
+fn test_peek_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("art.ans");
+    let data = with_record(100_000, 2);
+    fs::write(&path, &data).unwrap();
+
+    let header = SauceHeader::peek_path(&path).unwrap().unwrap();
+    assert_eq!(header, SauceHeader::from_bytes(&data).unwrap().unwrap());
+    assert_eq!(header.title, "Old");
+    assert_eq!(header.comments, 2);
+    assert_eq!(
+        SauceRecord::from_path(&path).unwrap(),
+        SauceRecord::from_bytes(&data).unwrap()
+    );
+
+    fs::write(&path, b"short").unwrap();
+    assert!(SauceHeader::peek_path(&path).unwrap().is_none());
+    assert!(SauceHeader::peek_path(&dir.path().join("missing")).is_err());
+}
@@ -0,0 +1,89 @@
+#![cfg(feature = "mmap")]
+
+use std::{fs, path::Path};
+
+use icy_sauce::{
+    MappedSauceFile, ParseOptions, SauceError, SauceFile, SauceRecord, SauceRecordBuilder,
+    StripMode, split_concatenated, strip_sauce,
+};
+
+mod common;
+use common::record;
+
+fn write_file(dir: &Path, data: &[u8]) -> std::path::PathBuf {
+    let path = dir.join("image.bin");
+    fs::write(&path, data).unwrap();
+    path
+}
+
+#[test]
+fn test_mapped_matches_slice_functions() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut data = vec![b'x'; 200_000];
+    record("First", 1).write(&mut data).unwrap();
+    data.extend(b"Second file");
+    record("Second", 1).write(&mut data).unwrap();
+    let path = write_file(dir.path(), &data);
+
+    let mapped = MappedSauceFile::open(&path).unwrap();
+    assert_eq!(mapped.bytes(), &data[..]);
+    assert_eq!(
+        mapped.record().unwrap(),
+        SauceRecord::from_path(&path).unwrap()
+    );
+    assert_eq!(
+        SauceRecord::from_path_mmap(&path).unwrap(),
+        Some(record("Second", 1))
+    );
+    for mode in [StripMode::Last, StripMode::AllStripFinalEof] {
+        assert_eq!(mapped.strip(mode), strip_sauce(&data, mode));
+    }
+    assert_eq!(mapped.find(), SauceRecord::find_in(&data));
+    assert_eq!(mapped.split(), split_concatenated(&data));
+    assert_eq!(
+        mapped.sauce_file().unwrap().to_bytes(),
+        SauceFile::from_bytes(&data).unwrap().to_bytes()
+    );
+}
+
+#[test]
+fn test_min_confidence_sees_whole_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut data = vec![b'x'; 40_000];
+    SauceRecordBuilder::default()
+        .file_size(40_000)
+        .build()
+        .write(&mut data)
+        .unwrap();
+    let path = write_file(dir.path(), &data);
+
+    let options = ParseOptions {
+        min_confidence: 90,
+        ..Default::default()
+    };
+    let mapped = MappedSauceFile::open(&path).unwrap();
+    assert_eq!(
+        mapped.record_with_options(&options).unwrap(),
+        SauceRecord::from_bytes_with_options(&data, &options).unwrap()
+    );
+    assert!(mapped.record_with_options(&options).unwrap().is_some());
+}
+
+#[test]
+fn test_empty_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = write_file(dir.path(), b"");
+    let mapped = MappedSauceFile::open(&path).unwrap();
+    assert!(mapped.bytes().is_empty());
+    assert!(mapped.record().unwrap().is_none());
+    assert!(mapped.strip(StripMode::All).is_empty());
+}
+
+#[test]
+fn test_missing_file() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(matches!(
+        MappedSauceFile::open(&dir.path().join("missing")),
+        Err(SauceError::IoError { .. })
+    ));
+}
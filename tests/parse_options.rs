@@ -0,0 +1,151 @@
+use bstr::BString;
+use icy_sauce::{
+    ParseOptions, SauceDate, SauceError, SauceRecord, SauceRecordBuilder, header::SauceHeader,
+};
+
+fn record_with_comments() -> Vec<u8> {
+    let mut data = b"Content".to_vec();
+    SauceRecordBuilder::default()
+        .title(BString::from("Title"))
+        .unwrap()
+        .date(SauceDate::new(2025, 11, 8))
+        .add_comment(BString::from("First"))
+        .unwrap()
+        .add_comment(BString::from("Second"))
+        .unwrap()
+        .build()
+        .write(&mut data)
+        .unwrap();
+    data
+}
+
+fn corrupt_comment_id(data: &mut [u8]) {
+    let pos = data.len() - 128 - 2 * 64 - 5;
+    data[pos..pos + 5].copy_from_slice(b"XXXXX");
+}
+
+#[test]
+fn test_default_matches_from_bytes() {
+    let data = record_with_comments();
+    let a = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    let b = SauceRecord::from_bytes_with_options(&data, &ParseOptions::default())
+        .unwrap()
+        .unwrap();
+    assert!(a == b);
+}
+
+#[test]
+fn test_well_formed_record_passes_strict() {
+    let data = record_with_comments();
+    let record = SauceRecord::from_bytes_with_options(&data, &ParseOptions::strict())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.comments().len(), 2);
+}
+
+#[test]
+fn test_unknown_version() {
+    let mut data = record_with_comments();
+    let pos = data.len() - 128 + 5;
+    data[pos..pos + 2].copy_from_slice(b"01");
+
+    assert!(matches!(
+        SauceRecord::from_bytes(&data),
+        Err(SauceError::UnsupportedSauceVersion(_))
+    ));
+    let record = SauceRecord::from_bytes_with_options(&data, &ParseOptions::lenient())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.title(), &BString::from("Title"));
+    assert_eq!(record.comments().len(), 2);
+}
+
+#[test]
+fn test_missing_comment_id() {
+    let mut data = record_with_comments();
+    corrupt_comment_id(&mut data);
+
+    // Default: comments silently dropped
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert!(record.comments().is_empty());
+
+    // Strict: error
+    assert!(matches!(
+        SauceRecord::from_bytes_with_options(&data, &ParseOptions::strict()),
+        Err(SauceError::InvalidCommentBlock)
+    ));
+
+    // Lenient: comments recovered
+    let record = SauceRecord::from_bytes_with_options(&data, &ParseOptions::lenient())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.comments()[0], BString::from("First"));
+    assert_eq!(record.comments()[1], BString::from("Second"));
+}
+
+#[test]
+fn test_truncated_comment_block() {
+    let data = record_with_comments();
+    let truncated = &data[data.len() - 128 - 64..];
+
+    assert!(matches!(
+        SauceRecord::from_bytes(truncated),
+        Err(SauceError::InvalidCommentBlock)
+    ));
+    let record = SauceRecord::from_bytes_with_options(truncated, &ParseOptions::lenient())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.title(), &BString::from("Title"));
+    assert!(record.comments().is_empty());
+}
+
+#[test]
+fn test_invalid_date() {
+    let mut data = record_with_comments();
+    let pos = data.len() - 128 + 82;
+    data[pos..pos + 8].copy_from_slice(b"  /  /  ");
+
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert_eq!(record.date(), SauceDate::default());
+
+    match SauceRecord::from_bytes_with_options(&data, &ParseOptions::strict()) {
+        Err(SauceError::UnsupportedSauceDate(raw)) => assert_eq!(raw, BString::from("  /  /  ")),
+        _ => panic!("expected UnsupportedSauceDate"),
+    }
+    assert!(SauceHeader::from_bytes_with_options(&data, &ParseOptions::strict()).is_err());
+}
+
+#[test]
+fn test_missing_eof_marker() {
+    let record = SauceRecordBuilder::default()
+        .date(SauceDate::new(2025, 11, 8))
+        .build();
+    let mut data = b"Content".to_vec();
+    record.write_without_eof(&mut data).unwrap();
+
+    assert!(SauceRecord::from_bytes(&data).unwrap().is_some());
+    assert!(matches!(
+        SauceRecord::from_bytes_with_options(&data, &ParseOptions::strict()),
+        Err(SauceError::MissingEofMarker)
+    ));
+
+    let options = ParseOptions {
+        require_eof_marker: false,
+        ..ParseOptions::strict()
+    };
+    assert!(
+        SauceRecord::from_bytes_with_options(&data, &options)
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn test_from_path_with_options() {
+    let path = std::path::Path::new("tests/files/test2.ans");
+    let record = SauceRecord::from_path_with_options(path, &ParseOptions::lenient())
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.comments().len(), 2);
+}
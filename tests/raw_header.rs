@@ -0,0 +1,111 @@
+use bstr::BString;
+use icy_sauce::{RawSauceHeader, SauceDate, SauceRecordBuilder};
+
+fn sample() -> Vec<u8> {
+    let mut data = b"Content".to_vec();
+    SauceRecordBuilder::default()
+        .title(BString::from("Title"))
+        .unwrap()
+        .author(BString::from("Author"))
+        .unwrap()
+        .date(SauceDate::new(2025, 11, 8))
+        .add_comment(BString::from("Comment"))
+        .unwrap()
+        .build()
+        .write(&mut data)
+        .unwrap();
+    data
+}
+
+/// A record as written by an old tool: NUL padded title, malformed date, version "01".
+fn historical() -> Vec<u8> {
+    let mut data = sample();
+    let h = data.len() - 128;
+    data[h + 5..h + 7].copy_from_slice(b"01");
+    data[h + 12..h + 42].fill(0);
+    data[h + 82..h + 90].copy_from_slice(b"19xx0101");
+    data
+}
+
+#[test]
+fn test_untouched_round_trip_is_byte_exact() {
+    let data = historical();
+    let raw = RawSauceHeader::from_bytes(&data).unwrap();
+    let record = raw.to_record();
+    assert_eq!(record.title(), &BString::from("Title"));
+
+    let mut out = b"Content".to_vec();
+    record.write_preserving(&raw, &mut out).unwrap();
+    assert_eq!(out, data);
+
+    // The normalizing writer does change bytes
+    assert_ne!(&record.to_bytes()[..], &data[7..]);
+}
+
+#[test]
+fn test_only_modified_field_is_reencoded() {
+    let data = historical();
+    let h = data.len() - 128;
+    let mut raw = RawSauceHeader::from_bytes(&data).unwrap();
+    let record = raw
+        .to_record()
+        .to_builder()
+        .author(BString::from("Someone"))
+        .unwrap()
+        .build();
+    raw.apply(&record);
+
+    let out = raw.to_bytes_without_eof();
+    let oh = out.len() - 128;
+    assert_eq!(&out[oh + 42..oh + 62], b"Someone             ");
+    // Everything else is untouched
+    assert_eq!(&out[..oh + 42], &data[h - 69..h + 42]);
+    assert_eq!(&out[oh + 62..], &data[h + 62..]);
+}
+
+#[test]
+fn test_changed_comments_rewrite_block() {
+    let data = historical();
+    let h = data.len() - 128;
+    let mut raw = RawSauceHeader::from_bytes(&data).unwrap();
+    let record = raw
+        .to_record()
+        .to_builder()
+        .add_comment(BString::from("Second"))
+        .unwrap()
+        .build();
+    raw.apply(&record);
+
+    assert_eq!(raw.comment_block().len(), 5 + 2 * 64);
+    assert_eq!(raw.header_bytes()[104], 2);
+    assert_eq!(&raw.header_bytes()[..104], &data[h..h + 104]);
+    assert_eq!(raw.to_record().comments().len(), 2);
+}
+
+#[test]
+fn test_removing_comments_drops_block() {
+    let data = sample();
+    let mut raw = RawSauceHeader::from_bytes(&data).unwrap();
+    let record = SauceRecordBuilder::default()
+        .title(BString::from("Title"))
+        .unwrap()
+        .author(BString::from("Author"))
+        .unwrap()
+        .date(SauceDate::new(2025, 11, 8))
+        .build();
+    raw.apply(&record);
+
+    assert!(raw.comment_block().is_empty());
+    assert_eq!(raw.header_bytes()[104], 0);
+    assert_eq!(
+        &raw.header_bytes()[..104],
+        &data[data.len() - 128..data.len() - 24]
+    );
+    assert_eq!(raw.to_bytes().len(), 129);
+}
+
+#[test]
+fn test_from_bytes_without_sauce() {
+    assert!(RawSauceHeader::from_bytes(b"no sauce here").is_none());
+    assert!(RawSauceHeader::from_bytes(&[0u8; 200]).is_none());
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "std")]
+
+use std::io::Cursor;
+
+use bstr::BString;
+use icy_sauce::{
+    AttachPolicy, ParseOptions, SauceError, SauceRecord, SauceRecordBuilder, attach_sauce,
+};
+
+mod common;
+use common::{record, with_record};
+
+#[test]
+fn test_from_reader() {
+    let data = with_record(50_000, 3);
+    let mut cursor = Cursor::new(&data);
+    let parsed = SauceRecord::from_reader(&mut cursor).unwrap().unwrap();
+    assert_eq!(parsed, SauceRecord::from_bytes(&data).unwrap().unwrap());
+    assert_eq!(parsed.comments().len(), 3);
+    assert_eq!(cursor.position(), data.len() as u64);
+}
+
+#[test]
+fn test_from_reader_min_confidence_uses_real_length() {
+    let mut data = vec![b'x'; 40_000];
+    SauceRecordBuilder::default()
+        .title(BString::from("Genuine"))
+        .unwrap()
+        .file_size(40_000)
+        .add_comment(BString::from("Comment"))
+        .unwrap()
+        .build()
+        .write(&mut data)
+        .unwrap();
+    let options = ParseOptions {
+        min_confidence: 90,
+        ..Default::default()
+    };
+    assert!(
+        SauceRecord::from_bytes_with_options(&data, &options)
+            .unwrap()
+            .is_some()
+    );
+    let parsed = SauceRecord::from_reader_with_options(&mut Cursor::new(&data), &options).unwrap();
+    assert_eq!(parsed.unwrap().file_size(), 40_000);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("genuine.ans");
+    std::fs::write(&path, &data).unwrap();
+    assert!(
+        SauceRecord::from_path_with_options(&path, &options)
+            .unwrap()
+            .is_some()
+    );
+}
+
+#[test]
+fn test_from_reader_without_record() {
+    let mut cursor = Cursor::new(b"tiny".to_vec());
+    assert!(SauceRecord::from_reader(&mut cursor).unwrap().is_none());
+    let mut cursor = Cursor::new(Vec::new());
+    assert!(SauceRecord::from_reader(&mut cursor).unwrap().is_none());
+}
+
+#[test]
+fn test_from_reader_malformed_record() {
+    let mut data = with_record(10, 0);
+    let version = data.len() - 128 + 5;
+    data[version..version + 2].copy_from_slice(b"99");
+    assert!(matches!(
+        SauceRecord::from_reader(&mut Cursor::new(data)),
+        Err(SauceError::UnsupportedSauceVersion(_))
+    ));
+}
+
+#[test]
+fn test_write_to_matches_attach() {
+    let original = with_record(20_000, 1);
+    let mut expected = original.clone();
+    attach_sauce(&mut expected, &record("New", 1), &AttachPolicy::default()).unwrap();
+
+    let mut cursor = Cursor::new(original);
+    let summary = record("New", 1)
+        .write_to(&mut cursor, &AttachPolicy::default())
+        .unwrap();
+    assert_eq!(summary.new_len, expected.len() as u64);
+    assert_eq!(cursor.into_inner(), expected);
+}
+
+#[test]
+fn test_write_to_refuses_shrinking_record() {
+    let original = with_record(100, 4);
+    let mut cursor = Cursor::new(original.clone());
+    let err = record("New", 0)
+        .write_to(&mut cursor, &AttachPolicy::default())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        SauceError::EditWouldShrink { original_len, new_len }
+            if original_len == original.len() as u64 && new_len == original.len() as u64 - 4 * 64 - 5
+    ));
+
+    let data = cursor.into_inner();
+    assert_eq!(data, original);
+    let parsed = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert_eq!(parsed.comments().len(), 4);
+}
@@ -0,0 +1,108 @@
+use bstr::BString;
+use icy_sauce::{
+    CharacterCapabilities, CharacterFormat, SauceDataType, SauceDate, SauceError, SauceHeaderRef,
+    SauceRecord, SauceRecordBuilder, SauceRecordRef, header::SauceHeader,
+};
+
+fn full_record() -> SauceRecord {
+    SauceRecordBuilder::default()
+        .title(BString::from("Title"))
+        .unwrap()
+        .author(BString::from("Author"))
+        .unwrap()
+        .group(BString::from("Group"))
+        .unwrap()
+        .date(SauceDate::new(2025, 11, 8))
+        .file_size(1234)
+        .data_type(SauceDataType::Character)
+        .capabilities(icy_sauce::Capabilities::Character(
+            CharacterCapabilities::new(CharacterFormat::Ansi),
+        ))
+        .unwrap()
+        .add_comment(BString::from("First comment"))
+        .unwrap()
+        .add_comment(BString::from("Second comment"))
+        .unwrap()
+        .build()
+}
+
+#[test]
+fn test_fields_match_owned_record() {
+    let mut data = b"Content".to_vec();
+    full_record().write(&mut data).unwrap();
+
+    let view = SauceRecordRef::from_bytes(&data).unwrap().unwrap();
+    assert_eq!(view.title(), "Title");
+    assert_eq!(view.author(), "Author");
+    assert_eq!(view.group(), "Group");
+    assert_eq!(view.date(), SauceDate::new(2025, 11, 8));
+    assert_eq!(view.file_size(), 1234);
+    assert_eq!(view.data_type(), SauceDataType::Character);
+    let comments: Vec<_> = view.comments().collect();
+    assert_eq!(comments, ["First comment", "Second comment"]);
+    assert_eq!(view.to_owned(), full_record());
+
+    let header = view.header();
+    assert_eq!(header.bytes(), &data[data.len() - 128..]);
+    assert_eq!(header.comments(), 2);
+    assert_eq!(
+        header.to_owned(),
+        SauceHeader::from_bytes(&data).unwrap().unwrap()
+    );
+}
+
+#[test]
+fn test_to_owned_matches_from_bytes() {
+    let mut plain = b"Content".to_vec();
+    SauceRecordBuilder::default()
+        .build()
+        .write(&mut plain)
+        .unwrap();
+
+    // Comment block without COMNT ID is ignored
+    let mut no_id = b"Content".to_vec();
+    full_record().write(&mut no_id).unwrap();
+    let id = no_id.len() - 128 - 2 * 64 - 5;
+    no_id[id..id + 5].copy_from_slice(b"XXXXX");
+
+    // Invalid date reads as 0000/00/00
+    let mut bad_date = plain.clone();
+    let date = bad_date.len() - 128 + 82;
+    bad_date[date..date + 8].copy_from_slice(b"19xx0101");
+
+    for data in [&b"no record"[..], &plain, &no_id, &bad_date] {
+        let view = SauceRecordRef::from_bytes(data).unwrap();
+        assert_eq!(
+            view.map(|v| v.to_owned()),
+            SauceRecord::from_bytes(data).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_errors_match_from_bytes() {
+    let mut data = Vec::new();
+    full_record().write(&mut data).unwrap();
+
+    let mut version = data.clone();
+    let offset = version.len() - 128 + 5;
+    version[offset..offset + 2].copy_from_slice(b"01");
+    assert!(matches!(
+        SauceRecordRef::from_bytes(&version),
+        Err(SauceError::UnsupportedSauceVersion(_))
+    ));
+    assert!(matches!(
+        SauceHeaderRef::from_bytes(&version),
+        Err(SauceError::UnsupportedSauceVersion(_))
+    ));
+
+    let truncated = &data[data.len() - 200..];
+    assert!(matches!(
+        SauceRecordRef::from_bytes(truncated),
+        Err(SauceError::InvalidCommentBlock)
+    ));
+    assert!(matches!(
+        SauceRecord::from_bytes(truncated),
+        Err(SauceError::InvalidCommentBlock)
+    ));
+}
@@ -0,0 +1,105 @@
+use bstr::BString;
+use icy_sauce::{SauceDate, SauceFile, SauceRecord, SauceRecordBuilder};
+
+fn record(title: &str) -> SauceRecord {
+    SauceRecordBuilder::default()
+        .title(BString::from(title))
+        .unwrap()
+        .date(SauceDate::new(2025, 11, 8))
+        .add_comment(BString::from("comment"))
+        .unwrap()
+        .build()
+}
+
+#[test]
+fn test_plain_file() {
+    let data = b"Content\x1a";
+    let file = SauceFile::from_bytes(data).unwrap();
+    assert_eq!(file.content, data);
+    assert!(!file.eof);
+    assert!(file.record.is_none());
+    assert!(file.stacked.is_empty());
+    assert!(file.trailer.is_empty());
+    assert_eq!(file.to_bytes(), data);
+}
+
+#[test]
+fn test_single_record_round_trip() {
+    let mut data = b"Content".to_vec();
+    record("Title").write(&mut data).unwrap();
+
+    let file = SauceFile::from_bytes(&data).unwrap();
+    assert_eq!(file.content, b"Content");
+    assert!(file.eof);
+    assert_eq!(
+        file.record.as_ref().unwrap().title(),
+        &BString::from("Title")
+    );
+    assert_eq!(file.to_bytes(), data);
+}
+
+#[test]
+fn test_record_without_eof() {
+    let mut data = b"Content".to_vec();
+    record("Title").write_without_eof(&mut data).unwrap();
+
+    let file = SauceFile::from_bytes(&data).unwrap();
+    assert_eq!(file.content, b"Content");
+    assert!(!file.eof);
+    assert_eq!(file.to_bytes(), data);
+}
+
+#[test]
+fn test_stacked_records_and_trailer() {
+    let mut data = b"Content".to_vec();
+    record("Oldest").write(&mut data).unwrap();
+    record("Middle").write_without_eof(&mut data).unwrap();
+    record("Newest").write(&mut data).unwrap();
+    data.extend([0x1A; 20]);
+
+    let file = SauceFile::from_bytes(&data).unwrap();
+    assert_eq!(file.content, b"Content");
+    assert_eq!(
+        file.record.as_ref().unwrap().title(),
+        &BString::from("Newest")
+    );
+    assert!(file.eof);
+    let stacked: Vec<_> = file
+        .stacked
+        .iter()
+        .map(|s| (s.record.title().to_string(), s.eof))
+        .collect();
+    assert_eq!(
+        stacked,
+        [("Middle".to_string(), false), ("Oldest".to_string(), true)]
+    );
+    assert_eq!(file.trailer, &[0x1A; 20]);
+    assert_eq!(file.to_bytes(), data);
+}
+
+#[test]
+fn test_edit_and_serialize() {
+    let mut data = b"Content".to_vec();
+    record("Old").write(&mut data).unwrap();
+    record("New").write(&mut data).unwrap();
+
+    let mut file = SauceFile::from_bytes(&data).unwrap();
+    file.stacked.clear();
+    file.record = Some(record("Replaced"));
+
+    let mut expected = b"Content".to_vec();
+    record("Replaced").write(&mut expected).unwrap();
+    assert_eq!(file.to_bytes(), expected);
+
+    file.record = None;
+    assert_eq!(file.to_bytes(), b"Content");
+}
+
+#[test]
+fn test_malformed_record_is_error() {
+    let mut data = b"Content".to_vec();
+    record("Title").write(&mut data).unwrap();
+    let version = data.len() - 128 + 5;
+    data[version..version + 2].copy_from_slice(b"99");
+    assert!(SauceFile::from_bytes(&data).is_err());
+}
@@ -0,0 +1,64 @@
+use std::{sync::Arc, thread};
+
+use bstr::BString;
+use icy_sauce::{
+    Capabilities, CharacterCapabilities, CharacterFormat, SauceDataType, SauceError, SauceRecord,
+    SauceRecordBuilder, header::SauceHeader,
+};
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn public_types_are_send_and_sync() {
+    assert_send_sync::<SauceRecord>();
+    assert_send_sync::<SauceHeader>();
+    assert_send_sync::<Capabilities>();
+    assert_send_sync::<SauceError>();
+}
+
+#[test]
+fn record_can_be_shared_across_threads() {
+    let caps = CharacterCapabilities::new(CharacterFormat::Ansi);
+    let record = SauceRecordBuilder::default()
+        .title(BString::from("Shared"))
+        .unwrap()
+        .data_type(SauceDataType::Character)
+        .capabilities(Capabilities::Character(caps))
+        .unwrap()
+        .build();
+    let record = Arc::new(
+        SauceRecord::from_bytes(&record.to_bytes())
+            .unwrap()
+            .unwrap(),
+    );
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let record = Arc::clone(&record);
+            thread::spawn(move || (record.title().clone(), record.capabilities()))
+        })
+        .collect();
+    for handle in handles {
+        let (title, caps) = handle.join().unwrap();
+        assert_eq!(title, "Shared");
+        assert_eq!(caps, record.capabilities());
+        assert!(matches!(caps, Some(Capabilities::Character(_))));
+    }
+}
+
+#[test]
+fn parsed_record_equals_built_record() {
+    let caps = CharacterCapabilities::new(CharacterFormat::Ansi);
+    let built = SauceRecordBuilder::default()
+        .title(BString::from("Equal"))
+        .unwrap()
+        .data_type(SauceDataType::Character)
+        .capabilities(Capabilities::Character(caps))
+        .unwrap()
+        .add_comment(BString::from("Comment"))
+        .unwrap()
+        .build();
+    let parsed = SauceRecord::from_bytes(&built.to_bytes()).unwrap().unwrap();
+    assert_eq!(parsed, built);
+    assert_eq!(parsed.capabilities(), built.capabilities());
+}
@@ -0,0 +1,110 @@
+use bstr::BString;
+use icy_sauce::{SauceDate, SauceRecordBuilder, split_concatenated};
+
+fn artwork(content: &[u8], title: &str, with_size: bool) -> Vec<u8> {
+    let mut data = content.to_vec();
+    SauceRecordBuilder::default()
+        .title(BString::from(title))
+        .unwrap()
+        .date(SauceDate::new(1996, 4, 1))
+        .file_size(if with_size { content.len() as u32 } else { 0 })
+        .add_comment(BString::from("comment"))
+        .unwrap()
+        .build()
+        .write(&mut data)
+        .unwrap();
+    data
+}
+
+fn titles(data: &[u8]) -> Vec<Option<String>> {
+    split_concatenated(data)
+        .iter()
+        .map(|p| p.record.as_ref().map(|r| r.title().to_string()))
+        .collect()
+}
+
+#[test]
+fn test_empty_and_plain() {
+    assert!(split_concatenated(b"").is_empty());
+
+    let parts = split_concatenated(b"just text");
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].content, b"just text");
+    assert!(parts[0].record.is_none());
+}
+
+#[test]
+fn test_single_artwork() {
+    let data = artwork(b"\x1b[1mHello", "One", true);
+    let parts = split_concatenated(&data);
+    assert_eq!(parts.len(), 1);
+    assert_eq!(parts[0].content, b"\x1b[1mHello");
+    assert_eq!(parts[0].range, 0..data.len());
+}
+
+#[test]
+fn test_three_artworks() {
+    let mut data = artwork(b"first", "A", true);
+    data.extend(artwork(b"second artwork", "B", false));
+    data.extend(artwork(b"third", "C", true));
+
+    let parts = split_concatenated(&data);
+    assert_eq!(
+        titles(&data),
+        [Some("A".into()), Some("B".into()), Some("C".into())]
+    );
+    assert_eq!(parts[0].content, b"first");
+    assert_eq!(parts[1].content, b"second artwork");
+    assert_eq!(parts[2].content, b"third");
+    assert_eq!(parts[1].range.start, parts[0].range.end);
+    assert_eq!(parts[2].range.end, data.len());
+}
+
+#[test]
+fn test_file_size_finds_leading_file_without_sauce() {
+    let mut data = b"plain.txt without metadata\r\n".to_vec();
+    data.extend(artwork(b"art", "Art", true));
+
+    let parts = split_concatenated(&data);
+    assert_eq!(titles(&data), [None, Some("Art".into())]);
+    assert_eq!(parts[0].content, b"plain.txt without metadata\r\n");
+    assert_eq!(parts[1].content, b"art");
+}
+
+#[test]
+fn test_trailing_file_without_sauce() {
+    let mut data = artwork(b"art", "Art", true);
+    data.extend(b"readme");
+
+    let parts = split_concatenated(&data);
+    assert_eq!(titles(&data), [Some("Art".into()), None]);
+    assert_eq!(parts[1].content, b"readme");
+}
+
+#[test]
+fn test_stacked_records_stay_in_one_part() {
+    let mut data = artwork(b"first", "A", true);
+    data.extend(artwork(b"", "A2", false));
+    let with_eof = data.len();
+    SauceRecordBuilder::default()
+        .title(BString::from("A3"))
+        .unwrap()
+        .build()
+        .write_without_eof(&mut data)
+        .unwrap();
+    data.extend(artwork(b"second", "B", true));
+
+    let parts = split_concatenated(&data);
+    assert_eq!(titles(&data), [Some("A3".into()), Some("B".into())]);
+    assert_eq!(parts[0].content, b"first");
+    assert_eq!(parts[0].range, 0..with_eof + 128);
+    assert_eq!(parts[1].content, b"second");
+    assert_eq!(parts[1].range, with_eof + 128..data.len());
+}
+
+#[test]
+fn test_text_mentioning_signature_is_not_split() {
+    let mut data = b"The record starts with SAUCE00 and ".to_vec();
+    data.extend([b'x'; 200]);
+    assert_eq!(split_concatenated(&data).len(), 1);
+}
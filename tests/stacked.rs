@@ -0,0 +1,77 @@
+use bstr::BString;
+use icy_sauce::{SauceRecord, StripMode, strip_sauce};
+
+mod common;
+use common::record;
+
+#[test]
+fn test_no_sauce() {
+    assert!(
+        SauceRecord::stack_from_bytes(b"Content")
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_single_record() {
+    let mut data = b"Content".to_vec();
+    record("Only", 0).write(&mut data).unwrap();
+
+    let stack = SauceRecord::stack_from_bytes(&data).unwrap();
+    assert_eq!(stack.len(), 1);
+    assert_eq!(stack[0].range, 8..data.len());
+    assert!(stack[0].eof_marker);
+}
+
+#[test]
+fn test_three_records_outermost_first() {
+    let mut data = b"Content".to_vec();
+    record("First", 1).write(&mut data).unwrap();
+    record("Second", 0).write_without_eof(&mut data).unwrap();
+    record("Third", 2).write(&mut data).unwrap();
+
+    let stack = SauceRecord::stack_from_bytes(&data).unwrap();
+    let titles: Vec<_> = stack.iter().map(|l| l.record.title().to_string()).collect();
+    assert_eq!(titles, ["Third", "Second", "First"]);
+
+    assert_eq!(stack[2].range, 8..8 + 128 + 5 + 64);
+    assert!(stack[2].eof_marker);
+    assert_eq!(stack[1].range.start, stack[2].range.end);
+    assert!(!stack[1].eof_marker);
+    assert_eq!(stack[0].range.end, data.len());
+    assert_eq!(stack[0].record.comments().len(), 2);
+
+    // The innermost range ends where strip_sauce(All) leaves the data
+    assert_eq!(
+        strip_sauce(&data, StripMode::All).len(),
+        stack[2].range.start - 1
+    );
+}
+
+#[test]
+fn test_double_eof_stops_walk() {
+    let mut data = b"Content".to_vec();
+    record("First", 0).write(&mut data).unwrap();
+    data.push(0x1A);
+    record("Second", 0).write(&mut data).unwrap();
+
+    let stack = SauceRecord::stack_from_bytes(&data).unwrap();
+    assert_eq!(stack.len(), 1);
+    assert_eq!(stack[0].record.title(), &BString::from("Second"));
+}
+
+#[test]
+fn test_malformed_inner_record_ends_walk() {
+    let mut data = b"Content".to_vec();
+    record("First", 0).write(&mut data).unwrap();
+    let version = data.len() - 128 + 5;
+    data[version..version + 2].copy_from_slice(b"99");
+    record("Second", 0).write(&mut data).unwrap();
+
+    let stack = SauceRecord::stack_from_bytes(&data).unwrap();
+    assert_eq!(stack.len(), 1);
+
+    // A malformed outermost record is an error
+    assert!(SauceRecord::stack_from_bytes(&data[..version + 123]).is_err());
+}
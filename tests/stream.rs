@@ -0,0 +1,288 @@
+#![cfg(feature = "std")]
+
+use std::io::{Read, Write};
+
+use bstr::BString;
+use icy_sauce::{
+    ParseOptions, SauceAppendingWriter, SauceError, SauceRecord, SauceRecordBuilder,
+    SauceStrippingReader, StreamDetector, StripMode, detect_stream, strip_sauce, strip_sauce_ex,
+};
+
+mod common;
+use common::record;
+
+fn content(len: usize) -> Vec<u8> {
+    (0..len).map(|i| b'a' + (i % 26) as u8).collect()
+}
+
+fn feed_in_chunks(data: &[u8], chunk: usize) -> icy_sauce::StreamResult<Vec<u8>> {
+    let mut detector = StreamDetector::new(Vec::new());
+    for part in data.chunks(chunk) {
+        detector.feed(part).unwrap();
+    }
+    detector.finish().unwrap()
+}
+
+#[test]
+fn test_large_stream_in_chunks() {
+    let body = content(100_000);
+    let mut data = body.clone();
+    record("Streamed", 5).write(&mut data).unwrap();
+
+    for chunk in [1, 7, 4096, 16_000, 1_000_000] {
+        let result = feed_in_chunks(&data, chunk);
+        assert_eq!(
+            result.record,
+            Some(record("Streamed", 5)),
+            "chunk size {chunk}"
+        );
+        assert_eq!(result.content_len, body.len() as u64);
+        assert_eq!(result.total_len, data.len() as u64);
+        assert_eq!(result.sink, body);
+    }
+}
+
+#[test]
+fn test_stream_without_record() {
+    for len in [0, 10, 50_000] {
+        let body = content(len);
+        let result = detect_stream(&body[..], Vec::new()).unwrap();
+        assert!(result.record.is_none());
+        assert_eq!(result.content_len, len as u64);
+        assert_eq!(result.sink, body);
+    }
+}
+
+#[test]
+fn test_record_without_eof_and_stacked_records() {
+    let body = content(300);
+    let mut data = body.clone();
+    record("Streamed", 0).write_without_eof(&mut data).unwrap();
+    let result = detect_stream(&data[..], Vec::new()).unwrap();
+    assert_eq!(result.sink, body);
+
+    let mut data = body.clone();
+    record("Streamed", 1).write(&mut data).unwrap();
+    record("Streamed", 2).write(&mut data).unwrap();
+    let result = detect_stream(&data[..], Vec::new()).unwrap();
+    assert_eq!(result.record, Some(record("Streamed", 2)));
+    assert_eq!(
+        result.sink,
+        strip_sauce(&data, StripMode::LastStripFinalEof)
+    );
+}
+
+#[test]
+fn test_comment_block_without_comnt_id_is_not_content() {
+    let mut data = content(7);
+    record("Streamed", 1).write(&mut data).unwrap();
+    let comnt = data.len() - 128 - 64 - 5;
+    data[comnt..comnt + 5].copy_from_slice(b"XXXXX");
+
+    let result = detect_stream(&data[..], Vec::new()).unwrap();
+    assert!(result.record.unwrap().comments().is_empty());
+    assert_eq!(result.content_len, 7);
+    assert_eq!(result.sink, content(7));
+}
+
+#[test]
+fn test_lenient_truncated_comment_block() {
+    let mut data = content(10);
+    record("Streamed", 3).write(&mut data).unwrap();
+    let block = data.len() - 128 - 3 * 64 - 5;
+    data.drain(block..data.len() - 128);
+
+    let mut detector = StreamDetector::with_options(Vec::new(), ParseOptions::lenient());
+    detector.feed(&data).unwrap();
+    let result = detector.finish().unwrap();
+    assert!(result.record.unwrap().comments().is_empty());
+    assert_eq!(result.content_len, 10);
+    assert_eq!(result.sink, content(10));
+}
+
+#[test]
+fn test_write_impl() {
+    let body = content(20_000);
+    let mut data = body.clone();
+    record("Streamed", 0).write(&mut data).unwrap();
+
+    let mut detector = StreamDetector::new(Vec::new());
+    detector.write_all(&data).unwrap();
+    detector.flush().unwrap();
+    let result = detector.finish().unwrap();
+    assert!(result.record.is_some());
+    assert_eq!(result.sink, body);
+}
+
+#[test]
+fn test_malformed_record_is_error() {
+    let mut data = content(10);
+    record("Streamed", 0).write(&mut data).unwrap();
+    let version = data.len() - 128 + 5;
+    data[version..version + 2].copy_from_slice(b"99");
+    assert!(detect_stream(&data[..], Vec::new()).is_err());
+}
+
+/// A reader or writer that always fails.
+struct Broken;
+
+impl Read for Broken {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("broken"))
+    }
+}
+
+impl Write for Broken {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("broken"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_io_errors_name_the_failing_side() {
+    assert!(matches!(
+        detect_stream(Broken, Vec::new()),
+        Err(SauceError::IoError { path, .. }) if path.as_os_str() == "<reader>"
+    ));
+    let mut data = content(10);
+    record("Streamed", 0).write(&mut data).unwrap();
+    assert!(matches!(
+        detect_stream(&data[..], Broken),
+        Err(SauceError::IoError { path, .. }) if path.as_os_str() == "<writer>"
+    ));
+}
+
+fn read_stripped(
+    data: &[u8],
+    mode: StripMode,
+    chunk: usize,
+) -> (Vec<u8>, SauceStrippingReader<&[u8]>) {
+    let mut reader = SauceStrippingReader::new(data, mode);
+    let mut out = Vec::new();
+    let mut buf = vec![0u8; chunk];
+    loop {
+        let n = reader.read(&mut buf).unwrap();
+        if n == 0 {
+            break;
+        }
+        out.extend(&buf[..n]);
+    }
+    (out, reader)
+}
+
+#[test]
+fn test_stripping_reader_matches_strip_modes() {
+    let mut data = content(40_000);
+    data.push(0x1A);
+    record("Streamed", 2).write(&mut data).unwrap();
+    record("Streamed", 0).write(&mut data).unwrap();
+
+    for mode in [
+        StripMode::Last,
+        StripMode::LastStripFinalEof,
+        StripMode::All,
+        StripMode::AllStripFinalEof,
+    ] {
+        let expected = strip_sauce_ex(&data, mode);
+        for chunk in [1, 100, 65_536] {
+            let (out, reader) = read_stripped(&data, mode, chunk);
+            assert_eq!(out, expected.data, "{mode:?} chunk {chunk}");
+            assert!(reader.is_finished());
+            assert_eq!(reader.records_removed(), expected.records_removed);
+            assert_eq!(reader.record(), Some(&record("Streamed", 0)));
+        }
+    }
+}
+
+#[test]
+fn test_stripping_reader_streams_before_end() {
+    let mut data = content(100_000);
+    record("Streamed", 0).write(&mut data).unwrap();
+
+    let mut reader = SauceStrippingReader::new(&data[..], StripMode::LastStripFinalEof);
+    let mut buf = [0u8; 1024];
+    let n = reader.read(&mut buf).unwrap();
+    assert!(n > 0);
+    assert_eq!(&buf[..n], &data[..n]);
+    assert!(!reader.is_finished());
+    assert!(reader.record().is_none());
+
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(n + rest.len(), 100_000);
+    assert!(reader.record().is_some());
+}
+
+#[test]
+fn test_stripping_reader_without_record() {
+    for len in [0, 5, 30_000] {
+        let data = content(len);
+        let (out, reader) = read_stripped(&data, StripMode::AllStripFinalEof, 4096);
+        assert_eq!(out, data);
+        assert_eq!(reader.records_removed(), 0);
+        assert!(reader.record().is_none());
+    }
+}
+
+#[test]
+fn test_appending_writer() {
+    let body = content(50_000);
+    let builder = SauceRecordBuilder::default()
+        .title(BString::from("Export"))
+        .unwrap();
+    let mut writer = SauceAppendingWriter::new(Vec::new(), builder);
+    for part in body.chunks(999) {
+        writer.write_all(part).unwrap();
+    }
+    writer.flush().unwrap();
+    assert_eq!(writer.bytes_written(), 50_000);
+    *writer.builder_mut() = writer
+        .builder_mut()
+        .clone()
+        .author(BString::from("Artist"))
+        .unwrap();
+    let (data, record) = writer.finish().unwrap();
+
+    assert_eq!(record.file_size(), 50_000);
+    assert_eq!(record.author(), &BString::from("Artist"));
+    assert_eq!(&data[..50_000], &body[..]);
+    assert_eq!(data[50_000], 0x1A);
+    assert_eq!(SauceRecord::from_bytes(&data).unwrap(), Some(record));
+}
+
+#[test]
+fn test_appending_writer_empty_content() {
+    let writer = SauceAppendingWriter::new(Vec::new(), SauceRecordBuilder::default());
+    let (data, record) = writer.finish().unwrap();
+    assert_eq!(record.file_size(), 0);
+    assert_eq!(data.len(), 1 + 128);
+}
+
+#[test]
+fn test_appending_writer_too_large() {
+    /// Discards everything, so the test doesn't need 4 GiB of memory.
+    struct Discard;
+    impl Write for Discard {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = SauceAppendingWriter::new(Discard, SauceRecordBuilder::default());
+    let chunk = vec![0u8; 1 << 20];
+    for _ in 0..4096 {
+        writer.write_all(&chunk).unwrap();
+    }
+    writer.write_all(b"!").unwrap();
+    assert!(matches!(
+        writer.finish(),
+        Err(SauceError::FileTooLarge(4_294_967_297))
+    ));
+}
@@ -0,0 +1,164 @@
+use bstr::BString;
+use icy_sauce::{
+    BinaryCapabilities, Capabilities, CharacterCapabilities, CharacterFormat, IssueKind,
+    SauceDataType, SauceDate, SauceRecord, SauceRecordBuilder, Severity, header::SauceHeader,
+};
+
+fn valid_ansi_header() -> SauceHeader {
+    let caps = CharacterCapabilities::new(CharacterFormat::Ansi);
+    SauceRecordBuilder::default()
+        .date(SauceDate::new(2025, 11, 8))
+        .data_type(SauceDataType::Character)
+        .capabilities(Capabilities::Character(caps))
+        .unwrap()
+        .build()
+        .header()
+        .clone()
+}
+
+#[test]
+fn test_valid_record_has_no_issues() {
+    let file = std::fs::read("tests/files/test2.ans").unwrap();
+    let record = SauceRecord::from_bytes(&file).unwrap().unwrap();
+    let report = record.validate();
+    assert!(report.is_empty(), "unexpected issues: {:?}", report);
+}
+
+#[test]
+fn test_invalid_date() {
+    let mut header = valid_ansi_header();
+    header.date = SauceDate::default();
+    let report = header.validate();
+    assert_eq!(report.issues().len(), 1);
+    assert_eq!(report.issues()[0].field, "date");
+    assert_eq!(report.issues()[0].kind, IssueKind::InvalidDate);
+    assert_eq!(report.issues()[0].severity, Severity::Warning);
+}
+
+#[test]
+fn test_unparsable_date_from_bytes() {
+    let mut data = SauceRecordBuilder::default()
+        .date(SauceDate::new(2025, 11, 8))
+        .build()
+        .to_bytes();
+    let date_pos = data.len() - 128 + 82;
+    data[date_pos..date_pos + 8].copy_from_slice(b"ABCDEFGH");
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    assert!(
+        record
+            .validate()
+            .issues()
+            .iter()
+            .any(|i| i.kind == IssueKind::InvalidDate)
+    );
+}
+
+#[test]
+fn test_control_characters() {
+    let mut header = valid_ansi_header();
+    header.author = BString::from("Art\x1Bist");
+    let report = header.validate();
+    assert_eq!(report.issues().len(), 1);
+    assert_eq!(report.issues()[0].field, "author");
+    assert_eq!(report.issues()[0].kind, IssueKind::ControlCharacters);
+}
+
+#[test]
+fn test_reserved_flag_bits() {
+    let mut header = valid_ansi_header();
+    header.t_flags = 0b0001_1110;
+    let kinds: Vec<_> = header.validate().into_iter().map(|i| i.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            IssueKind::ReservedLetterSpacing,
+            IssueKind::ReservedAspectRatio
+        ]
+    );
+
+    header.t_flags = 0b1000_0000;
+    let kinds: Vec<_> = header.validate().into_iter().map(|i| i.kind).collect();
+    assert_eq!(kinds, vec![IssueKind::ReservedFlagBits(0b1000_0000)]);
+}
+
+#[test]
+fn test_binary_text_rules() {
+    let mut header = SauceHeader {
+        date: SauceDate::new(2025, 1, 1),
+        ..Default::default()
+    };
+    BinaryCapabilities::binary_text(160)
+        .unwrap()
+        .encode_into_header(&mut header)
+        .unwrap();
+    assert!(header.validate().is_empty());
+
+    header.file_type = 0;
+    header.t_info1 = 80;
+    let report = header.validate();
+    assert!(report.has_errors());
+    let issues = report.issues();
+    assert_eq!(issues[0].kind, IssueKind::InvalidBinaryTextWidth(0));
+    assert_eq!(issues[1].field, "t_info1");
+    assert_eq!(issues[1].kind, IssueKind::ReservedFieldSet);
+}
+
+#[test]
+fn test_reserved_fields_per_type() {
+    let mut header = SauceHeader {
+        date: SauceDate::new(2025, 1, 1),
+        data_type: SauceDataType::Archive,
+        ..Default::default()
+    };
+    assert!(header.validate().is_empty());
+    header.t_info3 = 1;
+    header.t_info_s = BString::from("font");
+    let fields: Vec<_> = header.validate().into_iter().map(|i| i.field).collect();
+    assert_eq!(fields, vec!["t_info3", "t_info_s"]);
+
+    header.data_type = SauceDataType::Undefined(42);
+    let report = header.validate();
+    assert_eq!(report.issues()[0].kind, IssueKind::UnknownDataType(42));
+}
+
+#[test]
+fn test_file_size_mismatch() {
+    let record = SauceRecordBuilder::default()
+        .date(SauceDate::new(2025, 11, 8))
+        .file_size(100)
+        .build();
+    assert!(record.validate_with_content_len(100).is_empty());
+    let report = record.validate_with_content_len(99);
+    assert_eq!(report.max_severity(), Some(Severity::Warning));
+    assert_eq!(report.issues()[0].field, "file_size");
+
+    // FileSize 0 means unknown
+    let record = SauceRecordBuilder::default()
+        .date(SauceDate::new(2025, 11, 8))
+        .build();
+    assert!(record.validate_with_content_len(1234).is_empty());
+}
+
+#[test]
+fn test_comment_count_mismatch() {
+    let mut data = SauceRecordBuilder::default()
+        .date(SauceDate::new(2025, 11, 8))
+        .add_comment(BString::from("Comment"))
+        .unwrap()
+        .build()
+        .to_bytes();
+    // Corrupt the COMNT id, parsing keeps the count but drops the lines
+    let comment_id_pos = data.len() - 128 - 64 - 5;
+    data[comment_id_pos..comment_id_pos + 5].copy_from_slice(b"WRONG");
+    let record = SauceRecord::from_bytes(&data).unwrap().unwrap();
+    let report = record.validate();
+    assert!(report.has_errors());
+    assert_eq!(
+        report.issues()[0].kind,
+        IssueKind::CommentCountMismatch {
+            declared: 1,
+            actual: 0
+        }
+    );
+    assert_eq!(report.at_least(Severity::Error).count(), 1);
+}